@@ -61,6 +61,7 @@ where
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
     C::Api: mmr_rpc::MmrRuntimeApi<Block, <Block as sp_runtime::traits::Block>::Hash, BlockNumber>,
     C::Api: leaf_provider_rpc::LeafProviderRuntimeAPI<Block>,
+    C::Api: xcm_app_rpc::XcmAppRuntimeAPI<Block, AccountId>,
     C::Api: sp_beefy::BeefyApi<Block>,
     C::Api: BlockBuilder<Block>,
     P: TransactionPool + Sync + Send + 'static,
@@ -70,6 +71,7 @@ where
     use mmr_rpc::{Mmr, MmrApiServer};
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
     use substrate_frame_rpc_system::{System, SystemApiServer};
+    use xcm_app_rpc::{XcmAppAPIServer, XcmAppClient};
 
     let mut module = RpcExtension::new(());
     let FullDeps { client, pool, deny_unsafe, beefy } = deps;
@@ -90,6 +92,7 @@ where
     )?;
 
     module.merge(LeafProviderClient::new(client.clone()).into_rpc())?;
+    module.merge(XcmAppClient::new(client.clone()).into_rpc())?;
     module.merge(BeefyLightClientClient::new(client).into_rpc())?;
     Ok(module)
 }
@@ -84,7 +84,13 @@ pub mod pallet {
             amount: u128,
         ) -> DispatchResultWithPostInfo {
             ensure_root(origin)?;
-            xcm_app::Pallet::<T>::xcm_transfer_asset(asset_id, sender, *recipient, amount)?;
+            xcm_app::Pallet::<T>::xcm_transfer_asset(
+                <T as xcm_app::Config>::DefaultSubNetworkId::get(),
+                asset_id,
+                sender,
+                *recipient,
+                amount,
+            )?;
             Ok(().into())
         }
 
@@ -95,15 +101,23 @@ pub mod pallet {
             asset_id: AssetId,
             multiasset: xcm::v3::AssetId,
             minimal_xcm_amount: u128,
+            metadata: xcm_app::AssetMetadata,
         ) -> DispatchResultWithPostInfo {
             ensure_root(origin)?;
-            let multilocation = match multiasset {
-                xcm::v3::AssetId::Concrete(location) => location,
-                xcm::v3::AssetId::Abstract(_) => frame_support::fail!(Error::<T>::WrongXCMVersion),
-            };
-
-            xcm_app::Pallet::<T>::register_mapping(asset_id, multilocation)?;
-            xcm_app::AssetMinimumAmount::<T>::set(multilocation, Some(minimal_xcm_amount));
+            match multiasset {
+                xcm::v3::AssetId::Concrete(multilocation) => {
+                    xcm_app::Pallet::<T>::register_mapping(asset_id, multilocation)?;
+                    xcm_app::AssetMinimumAmount::<T>::set(multilocation, Some(minimal_xcm_amount));
+                },
+                xcm::v3::AssetId::Abstract(abstract_id) => {
+                    xcm_app::Pallet::<T>::register_abstract_mapping(asset_id, abstract_id)?;
+                    xcm_app::AbstractAssetMinimumAmount::<T>::set(
+                        abstract_id,
+                        Some(minimal_xcm_amount),
+                    );
+                },
+            }
+            xcm_app::Pallet::<T>::do_set_asset_metadata(asset_id, Some(metadata))?;
 
             Ok(().into())
         }
@@ -115,10 +129,7 @@ pub mod pallet {
             asset_id: AssetId,
         ) -> DispatchResultWithPostInfo {
             ensure_root(origin)?;
-            xcm_app::Pallet::<T>::delete_mapping(asset_id)?;
-            if let Some(ml) = xcm_app::AssetIdToMultilocation::<T>::get(asset_id) {
-                xcm_app::AssetMinimumAmount::<T>::remove(ml);
-            }
+            xcm_app::Pallet::<T>::do_delete_mapping(asset_id)?;
             Ok(().into())
         }
     }
@@ -0,0 +1,168 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use codec::Codec;
+use jsonrpsee::{
+    core::{Error as RpcError, RpcResult},
+    proc_macros::rpc,
+    types::error::CallError,
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::H256;
+use sp_runtime::traits::Block as BlockT;
+use sp_weights::Weight;
+use std::{marker::PhantomData, sync::Arc};
+
+pub use xcm_app_runtime_api::{
+    AssetMetadata, TrappedMessage, TrappedMessageFilter, XcmAppAPI as XcmAppRuntimeAPI,
+};
+
+#[rpc(client, server)]
+pub trait XcmAppAPI<BlockHash, AccountId> {
+    #[method(name = "xcmApp_trappedMessages")]
+    fn trapped_messages(
+        &self,
+        filter: TrappedMessageFilter<AccountId>,
+        offset: u32,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(u128, TrappedMessage<AccountId>)>>;
+
+    #[method(name = "xcmApp_trappedDoneResults")]
+    fn trapped_done_results(&self, at: Option<BlockHash>) -> RpcResult<Vec<H256>>;
+
+    #[method(name = "xcmApp_bridgeAssetTrapNonce")]
+    fn bridge_asset_trap_nonce(&self, at: Option<BlockHash>) -> RpcResult<u128>;
+
+    #[method(name = "xcmApp_destinationWeightLimit")]
+    fn destination_weight_limit(
+        &self,
+        para_id: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<Weight>>;
+
+    #[method(name = "xcmApp_assetMetadata")]
+    fn asset_metadata(
+        &self,
+        asset_id: H256,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<AssetMetadata>>;
+
+    #[method(name = "xcmApp_feeForWeight")]
+    fn fee_for_weight(
+        &self,
+        asset_id: H256,
+        weight: Weight,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<u128>>;
+}
+
+pub struct XcmAppClient<C, B> {
+    client: Arc<C>,
+    _marker: PhantomData<B>,
+}
+
+impl<C, B> XcmAppClient<C, B> {
+    /// Construct default `XcmAppClient`.
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client, _marker: Default::default() }
+    }
+}
+
+impl<C, B, AccountId> XcmAppAPIServer<<B as BlockT>::Hash, AccountId> for XcmAppClient<C, B>
+where
+    B: BlockT,
+    C: Send + Sync + 'static,
+    C: ProvideRuntimeApi<B> + HeaderBackend<B>,
+    C::Api: XcmAppRuntimeAPI<B, AccountId>,
+    AccountId: Codec + serde::Serialize + serde::de::DeserializeOwned + Send + Sync + 'static,
+{
+    fn trapped_messages(
+        &self,
+        filter: TrappedMessageFilter<AccountId>,
+        offset: u32,
+        limit: u32,
+        at: Option<<B as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(u128, TrappedMessage<AccountId>)>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.trapped_messages(at, filter, offset, limit)
+            .map_err(|e| RpcError::Call(CallError::Failed(e.into())))
+    }
+
+    fn trapped_done_results(&self, at: Option<<B as BlockT>::Hash>) -> RpcResult<Vec<H256>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.trapped_done_results(at)
+            .map_err(|e| RpcError::Call(CallError::Failed(e.into())))
+    }
+
+    fn bridge_asset_trap_nonce(&self, at: Option<<B as BlockT>::Hash>) -> RpcResult<u128> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.bridge_asset_trap_nonce(at)
+            .map_err(|e| RpcError::Call(CallError::Failed(e.into())))
+    }
+
+    fn destination_weight_limit(
+        &self,
+        para_id: u32,
+        at: Option<<B as BlockT>::Hash>,
+    ) -> RpcResult<Option<Weight>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.destination_weight_limit(at, para_id)
+            .map_err(|e| RpcError::Call(CallError::Failed(e.into())))
+    }
+
+    fn asset_metadata(
+        &self,
+        asset_id: H256,
+        at: Option<<B as BlockT>::Hash>,
+    ) -> RpcResult<Option<AssetMetadata>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.asset_metadata(at, asset_id)
+            .map_err(|e| RpcError::Call(CallError::Failed(e.into())))
+    }
+
+    fn fee_for_weight(
+        &self,
+        asset_id: H256,
+        weight: Weight,
+        at: Option<<B as BlockT>::Hash>,
+    ) -> RpcResult<Option<u128>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.fee_for_weight(at, asset_id, weight)
+            .map_err(|e| RpcError::Call(CallError::Failed(e.into())))
+    }
+}
@@ -0,0 +1,67 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_core::H256;
+use sp_std::prelude::*;
+use sp_weights::Weight;
+pub use xcm_app::{AssetMetadata, TrappedMessage, TrappedMessageFilter};
+
+sp_api::decl_runtime_apis! {
+    pub trait XcmAppAPI<AccountId>
+    where
+        AccountId: Codec,
+    {
+        /// Returns trapped messages with nonces sorted by nonce
+        fn trapped_messages(
+            filter: TrappedMessageFilter<AccountId>,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<(u128, TrappedMessage<AccountId>)>;
+
+        /// Returns message ids of trapped successful transfer reports
+        fn trapped_done_results() -> Vec<H256>;
+
+        /// Returns the nonce of the last trapped message
+        fn bridge_asset_trap_nonce() -> u128;
+
+        /// Returns XCM weight limit for transfers to the parachain, None if transfers are unlimited
+        fn destination_weight_limit(para_id: u32) -> Option<Weight>;
+
+        /// Returns metadata of the asset
+        fn asset_metadata(asset_id: H256) -> Option<AssetMetadata>;
+
+        /// Returns fee charged for XCM execution `weight` paid in the asset,
+        /// None if the asset has no multilocation mapping or the fee is not set
+        fn fee_for_weight(asset_id: H256, weight: Weight) -> Option<u128>;
+    }
+}
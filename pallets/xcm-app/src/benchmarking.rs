@@ -31,7 +31,10 @@
 use super::*;
 use crate::Pallet as XCMApp;
 use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
-use frame_support::{pallet_prelude::Weight, traits::EnsureOrigin};
+use frame_support::{
+    pallet_prelude::Weight,
+    traits::{EnsureOrigin, Get},
+};
 use frame_system::RawOrigin;
 use xcm::{
     latest::prelude::{AssetId as XCMAssetId, *},
@@ -70,6 +73,8 @@ benchmarks! {
         let amount = 500;
         XCMApp::<T>::register_asset(T::CallOrigin::try_successful_origin().unwrap(), asset_id, multilocation.into(), bridge_types::types::AssetKind::Thischain, 1000)
             .expect("transfer: Failed register asset");
+        let metadata = AssetMetadata { decimals: T::SoraDecimals::get(), ..test_metadata() };
+        XCMApp::<T>::set_asset_metadata(T::ManageOrigin::try_successful_origin().unwrap(), asset_id, Some(metadata))?;
     }: {
         XCMApp::<T>::transfer(T::CallOrigin::try_successful_origin().unwrap(), asset_id, alice::<T>(), multilocation.into(), amount)?;
     }
@@ -82,7 +87,7 @@ benchmarks! {
         let asset_id = [1; 32].into();
         let amount = 500;
         // trap_asset:
-        XCMApp::<T>::trap_asset(Some(message_id), asset_id, alice::<T>(), amount, true);
+        XCMApp::<T>::trap_asset(SubNetworkId::Mainnet, Some(message_id), asset_id, alice::<T>(), amount, true);
     }: _(RawOrigin::Root, 1)
     verify {
         assert!(XCMApp::<T>::bridge_asset_trap(1).is_none());
@@ -113,6 +118,182 @@ benchmarks! {
         let versioned_dest: bridge_types::substrate::VersionedMultiLocation = MultiLocation::parent().into();
         let versioned_msg = xcm::VersionedXcm::from(msg);
     }: _(RawOrigin::Root, Box::new(versioned_dest), Box::new(versioned_msg))
+
+    resend_trapped_done_result {
+        let message_id = [0; 32].into();
+        let sender = Some(frame_benchmarking::whitelisted_caller());
+        TrappedDoneResult::<T>::insert(
+            message_id,
+            TrappedDoneResultInfo { network_id: SubNetworkId::Mainnet, sender },
+        );
+    }: _(RawOrigin::Root, message_id)
+    verify {
+        assert!(!TrappedDoneResult::<T>::contains_key(message_id));
+        assert_event::<T>(Event::<T>::TrappedDoneResultSent(message_id).into());
+    }
+
+    set_asset_transfer_limit {
+        let asset_id = [1; 32].into();
+        let limit = TransferLimit {
+            max_transfer_amount: Some(1000),
+            max_period_volume: Some(10000),
+            period: 100u32.into(),
+        };
+    }: {
+        XCMApp::<T>::set_asset_transfer_limit(T::CallOrigin::try_successful_origin().unwrap(), asset_id, Some(limit))?;
+    }
+    verify {
+        assert_eq!(XCMApp::<T>::asset_transfer_limit(asset_id), Some(limit));
+    }
+
+    set_direction_paused {
+    }: _(RawOrigin::Root, TransferDirection::Outbound, true)
+    verify {
+        assert!(XCMApp::<T>::paused_direction(TransferDirection::Outbound).is_some());
+    }
+
+    set_asset_paused {
+        let asset_id = [1; 32].into();
+    }: _(RawOrigin::Root, asset_id, true)
+    verify {
+        assert!(XCMApp::<T>::paused_asset(asset_id).is_some());
+    }
+
+    change_asset_mapping {
+        let asset_id = [1; 32].into();
+        let multilocation = test_multilocation();
+        let new_multilocation = MultiLocation::parent();
+        XCMApp::<T>::register_asset(T::CallOrigin::try_successful_origin().unwrap(), asset_id, multilocation.into(), bridge_types::types::AssetKind::Thischain, 1000)
+            .expect("change_asset_mapping: Failed register asset");
+    }: {
+        XCMApp::<T>::change_asset_mapping(T::ManageOrigin::try_successful_origin().unwrap(), asset_id, new_multilocation.into())?;
+    }
+    verify {
+        assert_eq!(XCMApp::<T>::get_multilocation_from_asset_id(asset_id), Some(new_multilocation));
+        assert_eq!(XCMApp::<T>::asset_minimum_amount(new_multilocation), Some(1000));
+    }
+
+    change_multilocation_mapping {
+        let asset_id = [1; 32].into();
+        let new_asset_id = [2; 32].into();
+        let multilocation = test_multilocation();
+        XCMApp::<T>::register_asset(T::CallOrigin::try_successful_origin().unwrap(), asset_id, multilocation.into(), bridge_types::types::AssetKind::Thischain, 1000)
+            .expect("change_multilocation_mapping: Failed register asset");
+        XCMApp::<T>::set_asset_metadata(T::ManageOrigin::try_successful_origin().unwrap(), asset_id, Some(test_metadata()))?;
+    }: {
+        XCMApp::<T>::change_multilocation_mapping(T::ManageOrigin::try_successful_origin().unwrap(), multilocation.into(), new_asset_id)?;
+    }
+    verify {
+        assert_eq!(XCMApp::<T>::get_asset_id_from_multilocation(multilocation), Some(new_asset_id));
+        assert_eq!(XCMApp::<T>::asset_metadata(new_asset_id), Some(test_metadata()));
+    }
+
+    delete_mapping {
+        let asset_id = [1; 32].into();
+        let multilocation = test_multilocation();
+        XCMApp::<T>::register_asset(T::CallOrigin::try_successful_origin().unwrap(), asset_id, multilocation.into(), bridge_types::types::AssetKind::Thischain, 1000)
+            .expect("delete_mapping: Failed register asset");
+    }: {
+        XCMApp::<T>::delete_mapping(T::ManageOrigin::try_successful_origin().unwrap(), asset_id)?;
+    }
+    verify {
+        assert!(XCMApp::<T>::get_multilocation_from_asset_id(asset_id).is_none());
+        assert!(XCMApp::<T>::asset_minimum_amount(multilocation).is_none());
+    }
+
+    set_destination_weight_limit {
+        let weight = Weight::from_parts(1_000_000_000, 65536);
+    }: {
+        XCMApp::<T>::set_destination_weight_limit(T::ManageOrigin::try_successful_origin().unwrap(), 1000, Some(weight))?;
+    }
+    verify {
+        assert_eq!(XCMApp::<T>::destination_weight_limit(1000), Some(weight));
+    }
+
+    set_destination_fee {
+        let fee = DestinationFee { asset_id: [1; 32].into(), amount: 1000 };
+    }: {
+        XCMApp::<T>::set_destination_fee(T::ManageOrigin::try_successful_origin().unwrap(), 1000, Some(fee))?;
+    }
+    verify {
+        assert_eq!(XCMApp::<T>::destination_fee(1000), Some(fee));
+    }
+
+    send_to_mainnet {
+        let asset_id = [1; 32].into();
+        let caller = alice::<T>();
+        SoraNativeAssets::<T>::insert(asset_id, ());
+        T::LocalAssets::deposit(asset_id, &caller, 1000)?;
+    }: _(RawOrigin::Signed(caller.clone()), asset_id, caller.clone(), 1000)
+    verify {
+        assert_eq!(T::LocalAssets::free_balance(asset_id, &caller), 0);
+    }
+
+    set_sora_native_asset {
+        let asset_id = [1; 32].into();
+        SoraNativeAssets::<T>::insert(asset_id, ());
+    }: {
+        XCMApp::<T>::set_sora_native_asset(T::ManageOrigin::try_successful_origin().unwrap(), asset_id, false)?;
+    }
+    verify {
+        assert!(XCMApp::<T>::sora_native_asset(asset_id).is_none());
+    }
+
+    set_asset_metadata {
+        let asset_id = [1; 32].into();
+        let metadata = test_metadata();
+        XCMApp::<T>::register_asset(T::CallOrigin::try_successful_origin().unwrap(), asset_id, test_multilocation().into(), bridge_types::types::AssetKind::Thischain, 1000)
+            .expect("set_asset_metadata: Failed register asset");
+    }: {
+        XCMApp::<T>::set_asset_metadata(T::ManageOrigin::try_successful_origin().unwrap(), asset_id, Some(metadata.clone()))?;
+    }
+    verify {
+        assert_eq!(XCMApp::<T>::asset_metadata(asset_id), Some(metadata));
+    }
+
+    claim_asset_dust {
+        let asset_id = [1; 32].into();
+        let metadata = AssetMetadata { decimals: T::SoraDecimals::get().saturating_add(2), ..test_metadata() };
+        XCMApp::<T>::register_asset(T::CallOrigin::try_successful_origin().unwrap(), asset_id, test_multilocation().into(), bridge_types::types::AssetKind::Thischain, 1000)
+            .expect("claim_asset_dust: Failed register asset");
+        XCMApp::<T>::set_asset_metadata(T::ManageOrigin::try_successful_origin().unwrap(), asset_id, Some(metadata))?;
+        AssetDust::<T>::insert(asset_id, 1042);
+    }: {
+        XCMApp::<T>::claim_asset_dust(T::ManageOrigin::try_successful_origin().unwrap(), asset_id, alice::<T>())?;
+    }
+    verify {
+        assert_eq!(XCMApp::<T>::asset_dust(asset_id), 42);
+        assert_event::<T>(Event::<T>::DustClaimed(alice::<T>(), asset_id, 10).into());
+    }
+
+    set_asset_fee_per_second {
+        let asset_id = [1; 32].into();
+        XCMApp::<T>::register_asset(T::CallOrigin::try_successful_origin().unwrap(), asset_id, test_multilocation().into(), bridge_types::types::AssetKind::Thischain, 1000)
+            .expect("set_asset_fee_per_second: Failed register asset");
+    }: {
+        XCMApp::<T>::set_asset_fee_per_second(T::ManageOrigin::try_successful_origin().unwrap(), asset_id, Some(1000))?;
+    }
+    verify {
+        assert_eq!(XCMApp::<T>::asset_fee_per_second(test_multilocation()), Some(1000));
+    }
+
+    set_trusted_xcm_origin {
+        let location = test_multilocation();
+    }: {
+        XCMApp::<T>::set_trusted_xcm_origin(T::ManageOrigin::try_successful_origin().unwrap(), location.into(), true)?;
+    }
+    verify {
+        assert_eq!(XCMApp::<T>::trusted_xcm_origin(location), Some(()));
+    }
+
+    set_transact_call_allowed {
+        let location = test_multilocation();
+    }: {
+        XCMApp::<T>::set_transact_call_allowed(T::ManageOrigin::try_successful_origin().unwrap(), location.into(), (1, 2), true)?;
+    }
+    verify {
+        assert_eq!(XCMApp::<T>::transact_call_allowed(location, (1, 2)), Some(()));
+    }
 }
 
 impl_benchmark_test_suite!(XCMApp, crate::mock::new_test_ext(), crate::mock::Test,);
@@ -135,6 +316,16 @@ fn test_multilocation() -> MultiLocation {
     }
 }
 
+fn test_metadata() -> AssetMetadata {
+    // take the longest name and symbol
+    AssetMetadata {
+        name: scale_info::prelude::vec![b'A'; 32].try_into().unwrap(),
+        symbol: scale_info::prelude::vec![b'B'; 32].try_into().unwrap(),
+        decimals: 18,
+        existential_deposit: 1000,
+    }
+}
+
 fn assert_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
     let events = frame_system::Pallet::<T>::events();
     let system_event: <T as frame_system::Config>::RuntimeEvent = generic_event.into();
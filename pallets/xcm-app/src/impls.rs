@@ -29,8 +29,15 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use crate::*;
-use frame_support::{fail, traits::Get};
-use sp_runtime::traits::Convert;
+use core::marker::PhantomData;
+use frame_support::{
+    fail,
+    traits::{Contains, Get},
+    weights::Weight,
+};
+use sp_runtime::traits::{Convert, SaturatedConversion};
+use xcm::v3::Instruction;
+use xcm_executor::traits::ShouldExecute;
 
 // IMPLS
 impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
@@ -45,12 +52,13 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
         Default::default()
     }
 
-    fn total_issuance(_currency_id: Self::CurrencyId) -> Self::Balance {
+    /// Amount of the asset to be sent to Sora, but not forwarded to the bridge yet
+    fn total_issuance(currency_id: Self::CurrencyId) -> Self::Balance {
         log::trace!(
             target: "xcm::XCMApp",
             "total_issuance",
         );
-        Default::default()
+        Pallet::<T>::asset_accounting(currency_id).in_flight().saturated_into()
     }
 
     fn total_balance(_currency_id: Self::CurrencyId, _who: &T::AccountId) -> Self::Balance {
@@ -104,23 +112,32 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
             target: "xcm::XCMApp",
             "deposit",
         );
-        Pallet::<T>::add_to_channel(
-            who.clone(),
-            currency_id,
-            T::BalanceConverter::convert(amount),
-        )?;
+        Pallet::<T>::ensure_not_paused(TransferDirection::Inbound, currency_id)?;
+        let (amount, dust) =
+            Pallet::<T>::to_sora_amount(currency_id, T::BalanceConverter::convert(amount))?;
+        if dust > 0 {
+            Pallet::<T>::trap_dust(currency_id, dust);
+        }
+        Pallet::<T>::add_to_channel(who.clone(), currency_id, amount)?;
         Ok(())
     }
 
     fn withdraw(
-        _currency_id: Self::CurrencyId,
+        currency_id: Self::CurrencyId,
         _who: &T::AccountId,
-        _amount: Self::Balance,
+        amount: Self::Balance,
     ) -> sp_runtime::DispatchResult {
         log::trace!(
             target: "xcm::XCMApp",
             "withdraw",
         );
+        let amount = Pallet::<T>::to_sora_withdrawn_amount(
+            currency_id,
+            T::BalanceConverter::convert(amount),
+        )?;
+        Accounting::<T>::mutate(currency_id, |accounting| {
+            accounting.withdrawn = accounting.withdrawn.saturating_add(amount)
+        });
         Ok(())
     }
 
@@ -158,17 +175,8 @@ impl<T: Config> sp_runtime::traits::Convert<AssetId, Option<MultiLocation>> for
 }
 
 impl<T: Config> sp_runtime::traits::Convert<MultiLocation, Option<AssetId>> for Pallet<T> {
-    fn convert(mut multilocation: MultiLocation) -> Option<AssetId> {
-        // check if multilocations parent is 0, it means that an asset originates from Sora
-        // then convert it to absolute multilocation to check it
-        if multilocation.parents == 0 {
-            let mut self_location = T::SelfLocation::get();
-            if self_location.append_with(multilocation.interior).is_err() {
-                return None
-            }
-            multilocation = self_location;
-        }
-
+    fn convert(multilocation: MultiLocation) -> Option<AssetId> {
+        let multilocation = absolute_location::<T>(multilocation)?;
         let maybe_asset_id = Pallet::<T>::get_asset_id_from_multilocation(multilocation);
         if maybe_asset_id.is_none() {
             Self::deposit_event(Event::<T>::MultilocationMappingError(multilocation));
@@ -177,13 +185,135 @@ impl<T: Config> sp_runtime::traits::Convert<MultiLocation, Option<AssetId>> for
     }
 }
 
+/// Converts a multilocation with parent 0 to absolute multilocation,
+/// because it means that an asset originates from Sora
+fn absolute_location<T: Config>(multilocation: MultiLocation) -> Option<MultiLocation> {
+    if multilocation.parents != 0 {
+        return Some(multilocation)
+    }
+    let mut self_location = T::SelfLocation::get();
+    self_location.append_with(multilocation.interior).ok()?;
+    Some(self_location)
+}
+
 impl<T: Config> sp_runtime::traits::Convert<MultiAsset, Option<AssetId>> for Pallet<T> {
     fn convert(ma: MultiAsset) -> Option<AssetId> {
-        if let MultiAsset { fun: Fungible(_), id: Concrete(ml) } = ma {
-            Self::convert(ml)
-        } else {
-            Self::deposit_event(Event::<T>::MultiAssetMappingError(ma));
-            Option::None
+        match ma {
+            MultiAsset { fun: Fungible(_), id: Concrete(ml) } => Self::convert(ml),
+            MultiAsset { fun: Fungible(amount), id: Abstract(abstract_id) } => {
+                let maybe_asset_id = Pallet::<T>::get_asset_id_from_abstract(abstract_id);
+                if maybe_asset_id.is_none() {
+                    Self::deposit_event(Event::<T>::MultiAssetMappingError(ma));
+                }
+                // abstract assets can not pay for execution, so the minimum amount is checked here
+                let minimum_amount =
+                    Pallet::<T>::abstract_asset_minimum_amount(abstract_id).unwrap_or_default();
+                if amount < minimum_amount {
+                    log::trace!(
+                        target: "xcm::XCMApp",
+                        "abstract asset {:?} amount {} is below minimum {}",
+                        abstract_id,
+                        amount,
+                        minimum_amount,
+                    );
+                    return None
+                }
+                maybe_asset_id
+            },
+            _ => {
+                Self::deposit_event(Event::<T>::MultiAssetMappingError(ma));
+                Option::None
+            },
+        }
+    }
+}
+
+/// Relay chain and sibling parachains allowed to execute paid XCM, used in the XCM barrier
+pub struct TrustedXcmOrigin<T>(PhantomData<T>);
+
+impl<T: Config> Contains<MultiLocation> for TrustedXcmOrigin<T> {
+    fn contains(location: &MultiLocation) -> bool {
+        TrustedXcmOrigins::<T>::contains_key(location)
+    }
+}
+
+/// Wraps the XCM barrier to report messages rejected by it only because their origin is not trusted.
+/// `AnyOrigin` is the barrier the message would pass if its origin was trusted
+pub struct ReportUntrustedOrigin<Barrier, AnyOrigin, T>(PhantomData<(Barrier, AnyOrigin, T)>);
+
+impl<Barrier: ShouldExecute, AnyOrigin: ShouldExecute, T: Config> ShouldExecute
+    for ReportUntrustedOrigin<Barrier, AnyOrigin, T>
+{
+    fn should_execute<RuntimeCall>(
+        origin: &MultiLocation,
+        instructions: &mut [Instruction<RuntimeCall>],
+        max_weight: Weight,
+        weight_credit: &mut Weight,
+    ) -> Result<(), ()> {
+        let result = Barrier::should_execute(origin, instructions, max_weight, weight_credit);
+        if result.is_err() &&
+            !TrustedXcmOrigins::<T>::contains_key(origin) &&
+            AnyOrigin::should_execute(
+                origin,
+                instructions,
+                max_weight,
+                &mut weight_credit.clone(),
+            )
+            .is_ok()
+        {
+            log::trace!(
+                target: "xcm::XCMApp",
+                "message from untrusted origin {:?} rejected",
+                origin,
+            );
+            Pallet::<T>::deposit_event(Event::<T>::XcmOriginRejected(*origin));
+        }
+        result
+    }
+}
+
+/// Wraps the XCM barrier to reject messages carrying assets which inbound transfers are paused,
+/// before the trader takes any fee for them
+pub struct DenyPausedAssets<Barrier, T>(PhantomData<(Barrier, T)>);
+
+impl<Barrier: ShouldExecute, T: Config> DenyPausedAssets<Barrier, T> {
+    fn is_paused(asset: &MultiAsset) -> bool {
+        let asset_id = match asset {
+            MultiAsset { fun: Fungible(_), id: Concrete(ml) } => absolute_location::<T>(*ml)
+                .and_then(|ml| Pallet::<T>::get_asset_id_from_multilocation(ml)),
+            MultiAsset { fun: Fungible(_), id: Abstract(abstract_id) } =>
+                Pallet::<T>::get_asset_id_from_abstract(*abstract_id),
+            _ => None,
+        };
+        // unmapped assets are not transferred to Sora, so they are not checked
+        asset_id.map_or(false, |asset_id| {
+            Pallet::<T>::ensure_not_paused(TransferDirection::Inbound, asset_id).is_err()
+        })
+    }
+}
+
+impl<Barrier: ShouldExecute, T: Config> ShouldExecute for DenyPausedAssets<Barrier, T> {
+    fn should_execute<RuntimeCall>(
+        origin: &MultiLocation,
+        instructions: &mut [Instruction<RuntimeCall>],
+        max_weight: Weight,
+        weight_credit: &mut Weight,
+    ) -> Result<(), ()> {
+        let paused = instructions.iter().any(|instruction| match instruction {
+            Instruction::WithdrawAsset(assets) |
+            Instruction::ReserveAssetDeposited(assets) |
+            Instruction::ReceiveTeleportedAsset(assets) |
+            Instruction::ClaimAsset { assets, .. } => assets.inner().iter().any(Self::is_paused),
+            _ => false,
+        });
+        if paused {
+            log::trace!(
+                target: "xcm::XCMApp",
+                "message from {:?} with paused assets rejected",
+                origin,
+            );
+            return Err(())
         }
+        Barrier::should_execute(origin, instructions, max_weight, weight_credit)
     }
 }
@@ -37,20 +37,25 @@ mod mock;
 mod tests;
 
 mod impls;
+pub mod migrations;
 
 pub mod weights;
 
+pub use impls::{DenyPausedAssets, ReportUntrustedOrigin, TrustedXcmOrigin};
 pub use pallet::*;
 
 use crate::weights::WeightInfo;
-use bridge_types::{substrate::XCMAppCall, H256};
+use bridge_types::{substrate::XCMAppCall, SubNetworkId, H256};
 use codec::{Decode, Encode, MaxEncodedLen};
 use orml_traits::{xcm_transfer::XcmTransfer, MultiCurrency};
 use parachain_common::primitives::AssetId;
-use scale_info::prelude::boxed::Box;
+use scale_info::prelude::{boxed::Box, vec::Vec};
 use sp_runtime::{AccountId32, RuntimeDebug};
 use xcm::{
-    opaque::latest::{AssetId::Concrete, Fungibility::Fungible},
+    opaque::latest::{
+        AssetId::{Abstract, Concrete},
+        Fungibility::Fungible,
+    },
     v3::{MultiAsset, MultiLocation},
 };
 
@@ -81,10 +86,25 @@ where
     }
 }
 
+/// Successful transfer report which could not be sent back to Sora
 #[derive(
     Clone, RuntimeDebug, Encode, Decode, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen,
 )]
+pub struct TrappedDoneResultInfo<AccountId> {
+    /// Sora network the report should be sent to
+    pub network_id: SubNetworkId,
+    /// Sender of the transfer the report is sent on behalf of,
+    /// not known for results trapped before senders have been stored
+    pub sender: Option<AccountId>,
+}
+
+#[derive(
+    Clone, RuntimeDebug, Encode, Decode, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub struct TrappedMessage<AccountId> {
+    /// Sora network the message should be sent to
+    pub network_id: SubNetworkId,
     /// Trapped Sora Asset Id
     pub asset_id: AssetId,
     /// AccountId iof an account on Sora that should recieve tokens
@@ -97,6 +117,181 @@ pub struct TrappedMessage<AccountId> {
     pub is_refund: bool,
 }
 
+/// Limits of bridged transfers for an asset
+#[derive(
+    Clone, Copy, RuntimeDebug, Encode, Decode, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen,
+)]
+pub struct TransferLimit<BlockNumber> {
+    /// Maximum amount of a single transfer, not limited if None
+    pub max_transfer_amount: Option<u128>,
+    /// Maximum volume transferred during the period, not limited if None
+    pub max_period_volume: Option<u128>,
+    /// Length of the volume period in blocks
+    pub period: BlockNumber,
+}
+
+/// Maximum length of asset name and symbol
+pub type MaxMetadataLength = frame_support::traits::ConstU32<32>;
+
+/// Metadata of a bridged asset
+#[derive(
+    Clone, RuntimeDebug, Encode, Decode, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct AssetMetadata {
+    /// Asset name
+    pub name: frame_support::BoundedVec<u8, MaxMetadataLength>,
+    /// Asset symbol
+    pub symbol: frame_support::BoundedVec<u8, MaxMetadataLength>,
+    /// Number of decimals
+    pub decimals: u8,
+    /// Minimal balance of an account, a hint for wallets
+    pub existential_deposit: u128,
+}
+
+/// Decimals of the asset on Sora and on the chain it is transferred to or from
+#[derive(Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+pub struct DecimalsPair {
+    /// Decimals of the asset on Sora
+    pub sora: u8,
+    /// Decimals of the asset on the foreign chain
+    pub foreign: u8,
+}
+
+impl DecimalsPair {
+    /// Converts the amount from `from` to `to` decimals.
+    /// Returns converted amount and the remainder which can not be represented with `to` decimals,
+    /// None on overflow
+    fn convert(amount: u128, from: u8, to: u8) -> Option<(u128, u128)> {
+        if from >= to {
+            let factor = 10u128.checked_pow((from - to).into())?;
+            Some((amount / factor, amount % factor))
+        } else {
+            let factor = 10u128.checked_pow((to - from).into())?;
+            Some((amount.checked_mul(factor)?, 0))
+        }
+    }
+
+    /// Converts the amount in Sora decimals to the foreign decimals, the dust is in Sora decimals
+    pub fn to_foreign(&self, amount: u128) -> Option<(u128, u128)> {
+        Self::convert(amount, self.sora, self.foreign)
+    }
+
+    /// Converts the amount in foreign decimals to Sora decimals, the dust is in foreign decimals
+    pub fn to_sora(&self, amount: u128) -> Option<(u128, u128)> {
+        Self::convert(amount, self.foreign, self.sora)
+    }
+}
+
+/// Fee paid for transfers to a destination
+#[derive(
+    Clone, Copy, RuntimeDebug, Encode, Decode, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen,
+)]
+pub struct DestinationFee {
+    /// Sora AssetId of the asset the fee is paid in
+    pub asset_id: AssetId,
+    /// Amount of the fee asset in decimals of the destination
+    pub amount: u128,
+}
+
+/// Volume of bridged transfers of an asset in the current and the previous periods
+#[derive(
+    Clone,
+    Copy,
+    Default,
+    RuntimeDebug,
+    Encode,
+    Decode,
+    PartialEq,
+    Eq,
+    scale_info::TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct TransferVolume<BlockNumber> {
+    /// Block number the current period has been started at, a multiple of the period length
+    pub period_start: BlockNumber,
+    /// Amount transferred since the period start
+    pub volume: u128,
+    /// Amount transferred during the previous period
+    pub previous_volume: u128,
+}
+
+/// Accounting of an asset which passes through the parachain
+#[derive(
+    Clone,
+    Copy,
+    Default,
+    RuntimeDebug,
+    Encode,
+    Decode,
+    PartialEq,
+    Eq,
+    scale_info::TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct AssetAccounting {
+    /// Amount received from other chains through XCM to be sent to Sora
+    pub deposited: u128,
+    /// Amount sent back to Sora without passing through XCM, e.g. from local accounts
+    pub returned: u128,
+    /// Amount successfully submitted to the bridge outbound channel
+    pub forwarded: u128,
+    /// Amount received from Sora and withdrawn to be sent to other chains through XCM
+    pub withdrawn: u128,
+}
+
+impl AssetAccounting {
+    /// Amount to be sent to Sora, but not forwarded to the bridge yet.
+    /// Equals to the amount trapped by the bridge: `deposited + returned - forwarded`
+    pub fn in_flight(&self) -> u128 {
+        self.deposited.saturating_add(self.returned).saturating_sub(self.forwarded)
+    }
+
+    /// Net amount sent to other chains through XCM: `withdrawn - deposited`,
+    /// a negative value means that more has been received from other chains than sent to them.
+    /// Amounts returned to Sora without passing through XCM are not counted,
+    /// so it is not the balance of the transfers seen by Sora
+    pub fn net_withdrawn(&self) -> i128 {
+        use sp_runtime::traits::SaturatedConversion;
+        self.withdrawn
+            .saturated_into::<i128>()
+            .saturating_sub(self.deposited.saturated_into::<i128>())
+    }
+}
+
+/// Direction of bridged transfers
+#[derive(
+    Clone, Copy, RuntimeDebug, Encode, Decode, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen,
+)]
+pub enum TransferDirection {
+    /// Transfers from other chains to Sora
+    Inbound,
+    /// Transfers from Sora to other chains
+    Outbound,
+}
+
+/// Filter for trapped messages requested through runtime api
+#[derive(Clone, RuntimeDebug, Encode, Decode, PartialEq, Eq, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub enum TrappedMessageFilter<AccountId> {
+    /// All trapped messages
+    All,
+    /// Trapped messages with the given recipient
+    Recipient(AccountId),
+    /// Trapped messages with the given asset id
+    AssetId(AssetId),
+}
+
+impl<AccountId: PartialEq> TrappedMessageFilter<AccountId> {
+    pub fn matches(&self, message: &TrappedMessage<AccountId>) -> bool {
+        match self {
+            TrappedMessageFilter::All => true,
+            TrappedMessageFilter::Recipient(recipient) => &message.recipient == recipient,
+            TrappedMessageFilter::AssetId(asset_id) => &message.asset_id == asset_id,
+        }
+    }
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -110,11 +305,15 @@ pub mod pallet {
         dispatch::DispatchResultWithPostInfo,
         fail,
         pallet_prelude::*,
-        traits::{Currency, ExistenceRequirement, WithdrawReasons},
+        traits::{Currency, ExistenceRequirement, Imbalance, WithdrawReasons},
+        weights::WeightMeter,
     };
     use frame_system::{pallet_prelude::*, RawOrigin};
     use parachain_common::primitives::AssetId;
-    use sp_runtime::traits::{Convert, ConvertBack};
+    use sp_runtime::{
+        traits::{Convert, ConvertBack, SaturatedConversion, Saturating, Zero},
+        PerThing, Perbill,
+    };
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
@@ -155,10 +354,35 @@ pub mod pallet {
         type XorAssetId: Get<AssetId>;
 
         type Currency: Currency<Self::AccountId, Balance = u128>;
+
+        /// Store of Sora native assets held by local accounts, except XOR which is held by Currency
+        type LocalAssets: MultiCurrency<Self::AccountId, CurrencyId = AssetId, Balance = u128>;
+
+        /// Maximum number of trapped messages retried in `on_idle` per block
+        #[pallet::constant]
+        type MaxTrappedRetriesPerBlock: Get<u32>;
+
+        /// Governance origin which is allowed to manage the pallet along with CallOrigin
+        type ManageOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Origin which is allowed to pause and unpause transfers
+        type PauseOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Sora network for messages which are not initiated by the bridge
+        #[pallet::constant]
+        type DefaultSubNetworkId: Get<SubNetworkId>;
+
+        /// Decimals of assets on Sora, amounts are converted to the decimals from asset metadata
+        #[pallet::constant]
+        type SoraDecimals: Get<u8>;
     }
 
+    /// The current storage version
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[pallet::storage]
@@ -174,6 +398,7 @@ pub mod pallet {
     /// Traps some amount of asset that should be sent to some Account
     /// An asset can be trapped if a message could not be added to the outbound channel, for example due to queue limit
     /// The trapped asset can be released by try_claim_bridge_asset root extrinsic
+    /// or by claim_bridge_asset extrinsic signed by any account
     #[pallet::storage]
     #[pallet::getter(fn bridge_asset_trap)]
     pub type BridgeAssetTrap<T: Config> =
@@ -191,9 +416,120 @@ pub mod pallet {
         StorageMap<_, Blake2_256, MultiLocation, u128, OptionQuery>;
 
     /// Stores successful Done result of a message if the result could not be sent back to Sora
+    /// along with the Sora network and the sender the result should be sent with
     #[pallet::storage]
     #[pallet::getter(fn trapped_done_result)]
-    pub type TrappedDoneResult<T: Config> = StorageMap<_, Blake2_256, H256, (), OptionQuery>;
+    pub type TrappedDoneResult<T: Config> =
+        StorageMap<_, Blake2_256, H256, TrappedDoneResultInfo<T::AccountId>, OptionQuery>;
+
+    /// Message id of the trapped done result `on_idle` retries continue after,
+    /// so that a result which keeps failing does not hold back the others
+    #[pallet::storage]
+    pub type TrappedDoneResultRetryCursor<T: Config> = StorageValue<_, H256, OptionQuery>;
+
+    /// Nonce of the trapped message `on_idle` retries continue after,
+    /// so that a message which keeps failing does not hold back the others
+    #[pallet::storage]
+    pub type BridgeAssetTrapRetryCursor<T: Config> = StorageValue<_, u128, OptionQuery>;
+
+    /// Mapping of Sora AssetId to XCM abstract asset id
+    #[pallet::storage]
+    #[pallet::getter(fn get_abstract_from_asset_id)]
+    pub type AssetIdToAbstract<T: Config> =
+        StorageMap<_, Blake2_256, AssetId, [u8; 32], OptionQuery>;
+
+    /// Mapping of XCM abstract asset id to Sora AssetId
+    #[pallet::storage]
+    #[pallet::getter(fn get_asset_id_from_abstract)]
+    pub type AbstractToAssetId<T: Config> =
+        StorageMap<_, Blake2_256, [u8; 32], AssetId, OptionQuery>;
+
+    /// Minimum amount of an abstract asset to be transferred through XCM,
+    /// smaller amounts are not converted to Sora assets
+    #[pallet::storage]
+    #[pallet::getter(fn abstract_asset_minimum_amount)]
+    pub type AbstractAssetMinimumAmount<T: Config> =
+        StorageMap<_, Blake2_256, [u8; 32], u128, OptionQuery>;
+
+    /// Fee charged by XCM trader for a second of execution weight, in units of the asset
+    #[pallet::storage]
+    #[pallet::getter(fn asset_fee_per_second)]
+    pub type AssetFeePerSecond<T: Config> =
+        StorageMap<_, Blake2_256, MultiLocation, u128, OptionQuery>;
+
+    /// Locations of the relay chain and sibling parachains allowed to execute paid XCM on the parachain
+    #[pallet::storage]
+    #[pallet::getter(fn trusted_xcm_origin)]
+    pub type TrustedXcmOrigins<T: Config> =
+        StorageMap<_, Blake2_256, MultiLocation, (), OptionQuery>;
+
+    /// Calls the relay chain and sibling parachains are allowed to dispatch with XCM `Transact`,
+    /// by the origin location and the pallet and call indices of the call
+    #[pallet::storage]
+    #[pallet::getter(fn transact_call_allowed)]
+    pub type TransactAllowedCalls<T: Config> =
+        StorageDoubleMap<_, Blake2_256, MultiLocation, Blake2_256, (u8, u8), (), OptionQuery>;
+
+    /// Metadata of assets by Sora AssetId.
+    /// Amounts are converted between Sora decimals and the metadata decimals, passed unchanged if not set
+    #[pallet::storage]
+    #[pallet::getter(fn asset_metadata)]
+    pub type Metadata<T: Config> = StorageMap<_, Blake2_256, AssetId, AssetMetadata, OptionQuery>;
+
+    /// Dust of inbound transfers in foreign decimals, which can not be represented on Sora.
+    /// Covers remainders of withdrawals and can be claimed once it makes up a unit on Sora
+    #[pallet::storage]
+    #[pallet::getter(fn asset_dust)]
+    pub type AssetDust<T: Config> = StorageMap<_, Blake2_256, AssetId, u128, ValueQuery>;
+
+    /// Sora AssetIds of assets native to Sora, which can be held by local accounts
+    #[pallet::storage]
+    #[pallet::getter(fn sora_native_asset)]
+    pub type SoraNativeAssets<T: Config> = StorageMap<_, Blake2_256, AssetId, (), OptionQuery>;
+
+    /// Accounting of assets by Sora AssetId
+    #[pallet::storage]
+    #[pallet::getter(fn asset_accounting)]
+    pub type Accounting<T: Config> =
+        StorageMap<_, Blake2_256, AssetId, AssetAccounting, ValueQuery>;
+
+    /// Transfer directions which are paused
+    #[pallet::storage]
+    #[pallet::getter(fn paused_direction)]
+    pub type PausedDirections<T: Config> =
+        StorageMap<_, Blake2_256, TransferDirection, (), OptionQuery>;
+
+    /// Sora AssetIds which transfers are paused in both directions
+    #[pallet::storage]
+    #[pallet::getter(fn paused_asset)]
+    pub type PausedAssets<T: Config> = StorageMap<_, Blake2_256, AssetId, (), OptionQuery>;
+
+    /// Limits of bridged transfers by Sora AssetId, transfers are not limited if not set
+    #[pallet::storage]
+    #[pallet::getter(fn asset_transfer_limit)]
+    pub type AssetTransferLimit<T: Config> =
+        StorageMap<_, Blake2_256, AssetId, TransferLimit<BlockNumberFor<T>>, OptionQuery>;
+
+    /// Volume of bridged transfers by Sora AssetId in the current and the previous periods.
+    /// The volume of the last `period` blocks is approximated as the volume of the current period
+    /// plus the part of the previous period volume which is still in the rolling window
+    #[pallet::storage]
+    #[pallet::getter(fn asset_transfer_volume)]
+    pub type AssetTransferVolume<T: Config> =
+        StorageMap<_, Blake2_256, AssetId, TransferVolume<BlockNumberFor<T>>, ValueQuery>;
+
+    /// XCM weight limits by destination parachain id, unlimited if not set
+    #[pallet::storage]
+    #[pallet::getter(fn destination_weight_limit)]
+    pub type DestinationWeightLimit<T: Config> =
+        StorageMap<_, Blake2_256, u32, Weight, OptionQuery>;
+
+    /// Fees for transfers by destination parachain id, applied only to transfers of the fee asset.
+    /// Transfers of other assets pay the fee from the transferred amount
+    #[pallet::storage]
+    #[pallet::getter(fn destination_fee)]
+    pub type DestinationFees<T: Config> =
+        StorageMap<_, Blake2_256, u32, DestinationFee, OptionQuery>;
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -210,12 +546,21 @@ pub mod pallet {
         /// Mapping delete has been performed
         /// [Sora AssetId, XCM Multilocation]
         MappingDeleted(AssetId, MultiLocation),
+        /// Adding abstract mapping has been performed
+        /// [Sora AssetId, XCM abstract asset id]
+        AbstractMappingCreated(AssetId, [u8; 32]),
+        /// Abstract mapping delete has been performed
+        /// [Sora AssetId, XCM abstract asset id]
+        AbstractMappingDeleted(AssetId, [u8; 32]),
         /// Asset Added to channel
         /// [ParachainAppCall]
         AssetAddedToChannel(ParachainAppCall),
         /// Asset transfered from this parachain
         /// [From, To, AssedId, amount]
         AssetTransferred(T::AccountId, MultiLocation, AssetId, u128),
+        /// Asset credited to a local account
+        /// [To, AssetId, amount]
+        AssetDeposited(T::AccountId, AssetId, u128),
         /// [To, AssetId, amount, MessageId]
         AssetRefundSent(H256, T::AccountId, AssetId, u128),
         /// [To, AssetId, amount, MessageId]
@@ -241,6 +586,57 @@ pub mod pallet {
         BridgeAssetTrapped(u128, Option<H256>, T::AccountId, AssetId, u128),
         /// Successful message is trapped in XCM App due to Submitting to channel error
         DoneMessageTrapped(H256),
+        /// Trapped message has been resubmitted to the outbound channel in on_idle
+        /// [Nonce]
+        BridgeAssetTrapRetried(u128),
+        /// Trapped done result has been resent to the outbound channel
+        /// [MessageId]
+        TrappedDoneResultSent(H256),
+        /// Transfer limit of an asset has been set or removed
+        /// [AssetId, TransferLimit]
+        AssetTransferLimitSet(AssetId, Option<TransferLimit<BlockNumberFor<T>>>),
+        /// Transfer has been rejected due to the asset transfer limit
+        /// [AssetId, amount]
+        TransferThrottled(AssetId, u128),
+        /// Transfers in the direction have been paused or unpaused
+        /// [TransferDirection, paused]
+        DirectionPauseSet(TransferDirection, bool),
+        /// Transfers of the asset have been paused or unpaused
+        /// [AssetId, paused]
+        AssetPauseSet(AssetId, bool),
+        /// XCM weight limit for the destination parachain has been set or removed
+        /// [ParaId, Weight]
+        DestinationWeightLimitSet(u32, Option<Weight>),
+        /// Fee for transfers to the destination parachain has been set or removed
+        /// [ParaId, DestinationFee]
+        DestinationFeeSet(u32, Option<DestinationFee>),
+        /// Asset has been marked or unmarked as native to Sora
+        /// [AssetId, is_native]
+        SoraNativeAssetSet(AssetId, bool),
+        /// Metadata of the asset has been set or removed
+        /// [AssetId, AssetMetadata]
+        AssetMetadataSet(AssetId, Option<AssetMetadata>),
+        /// Dust of the inbound transfer has been trapped
+        /// [AssetId, Amount in foreign decimals]
+        DustTrapped(AssetId, u128),
+        /// Dust of the outbound transfer has been sent back to the sender
+        /// [Sender, AssetId, Amount in Sora decimals]
+        DustRefunded(T::AccountId, AssetId, u128),
+        /// Trapped dust of inbound transfers has been sent to the recipient on Sora
+        /// [Recipient, AssetId, Amount in Sora decimals]
+        DustClaimed(T::AccountId, AssetId, u128),
+        /// Fee charged by XCM trader for a second of execution weight has been set or removed
+        /// [AssetId, FeePerSecond]
+        AssetFeePerSecondSet(AssetId, Option<u128>),
+        /// Location has been added to or removed from trusted XCM origins
+        /// [Location, is_trusted]
+        TrustedXcmOriginSet(MultiLocation, bool),
+        /// XCM from the location which is not trusted has been rejected by the barrier
+        /// [Location]
+        XcmOriginRejected(MultiLocation),
+        /// Call has been allowed or disallowed for the location to dispatch with XCM `Transact`
+        /// [Location, (PalletIndex, CallIndex), is_allowed]
+        TransactCallAllowedSet(MultiLocation, (u8, u8), bool),
     }
 
     #[pallet::error]
@@ -261,10 +657,56 @@ pub mod pallet {
         InvalidTrappedMessage,
         /// Invalid asset id
         InvalidAssetId,
+        /// Trapped done result is not found
+        TrappedDoneResultNotFound,
+        /// Transfer exceeds the asset transfer limit
+        TransferLimitExceeded,
+        /// Transfer limit has volume cap, but period is zero
+        InvalidTransferLimit,
+        /// Transfers in this direction are paused
+        DirectionPaused,
+        /// Transfers of this asset are paused
+        AssetPaused,
+        /// Operation is not supported for this kind of XCM asset id
+        UnsupportedAssetIdKind,
+        /// Fee paid in the transferred asset is not less than the transferred amount
+        FeeExceedsAmount,
+        /// Asset is not native to Sora and can not be held by local accounts
+        AssetNotSoraNative,
+        /// Asset is still held by local accounts
+        LocalAssetInUse,
+        /// Deposit to a new account is below existential deposit
+        BelowExistentialDeposit,
+        /// Amount is zero after conversion to the other decimals
+        AmountTooSmall,
+        /// Amount overflows after conversion to the other decimals
+        AmountOverflow,
+        /// Call is not allowed for the origin to dispatch with XCM `Transact`
+        TransactCallNotAllowed,
+        /// Asset has no metadata, so its amounts can not be converted between decimals
+        MetadataNotExist,
+        /// Asset has amounts trapped on the parachain, which are not sent to Sora yet
+        AssetInFlight,
     }
 
     #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Retries trapped done results and messages trapped in BridgeAssetTrap
+        /// while the block has spare weight
+        fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let mut meter = WeightMeter::from_limit(remaining_weight);
+            let mut retries = T::MaxTrappedRetriesPerBlock::get();
+            if Self::retry_trapped_done_results(&mut meter, &mut retries) {
+                Self::retry_bridge_asset_trap(&mut meter, &mut retries);
+            }
+            meter.consumed
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), &'static str> {
+            Self::do_try_state()
+        }
+    }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
@@ -299,23 +741,34 @@ pub mod pallet {
                 (asset_id, multiasset),
                 res
             );
-            let multilocation = match multiasset {
-                xcm::v3::AssetId::Concrete(location) => location,
-                xcm::v3::AssetId::Abstract(_) => fail!(Error::<T>::WrongXCMVersion),
-            };
-
-            Self::register_mapping(asset_id, multilocation)?;
-            AssetMinimumAmount::<T>::set(multilocation, Some(minimal_xcm_amount));
+            match multiasset {
+                xcm::v3::AssetId::Concrete(multilocation) => {
+                    Self::register_mapping(asset_id, multilocation)?;
+                    AssetMinimumAmount::<T>::set(multilocation, Some(minimal_xcm_amount));
+                },
+                xcm::v3::AssetId::Abstract(abstract_id) => {
+                    Self::register_abstract_mapping(asset_id, abstract_id)?;
+                    AbstractAssetMinimumAmount::<T>::set(abstract_id, Some(minimal_xcm_amount));
+                },
+            }
+            if asset_kind == bridge_types::types::AssetKind::Thischain {
+                SoraNativeAssets::<T>::insert(asset_id, ());
+            }
 
             T::OutboundChannel::submit(
-                SubNetworkId::Mainnet,
+                res.network_id,
                 &RawOrigin::Root,
                 &ParachainAppCall::FinalizeAssetRegistration { asset_id, asset_kind }
                     .prepare_message(),
                 (),
             )?;
 
-            Self::deposit_event(Event::<T>::MappingCreated(asset_id, multilocation));
+            match multiasset {
+                xcm::v3::AssetId::Concrete(multilocation) =>
+                    Self::deposit_event(Event::<T>::MappingCreated(asset_id, multilocation)),
+                xcm::v3::AssetId::Abstract(abstract_id) =>
+                    Self::deposit_event(Event::<T>::AbstractMappingCreated(asset_id, abstract_id)),
+            }
             Ok(().into())
         }
 
@@ -327,51 +780,7 @@ pub mod pallet {
             nonce: u128,
         ) -> DispatchResultWithPostInfo {
             ensure_root(origin)?;
-            let Some(TrappedMessage {
-                asset_id,
-                recipient,
-                amount,
-                message_id,
-                is_refund,
-            }) = Self::bridge_asset_trap(nonce) else {
-                fail!(Error::<T>::TrappedMessageNotFound)
-            };
-            let raw_origin = Some(recipient.clone()).into();
-            let message_bytes = if is_refund {
-                // if we need to refund - send message to report that errror has occured
-                let Some(message_id) = message_id else {
-                    fail!(Error::<T>::InvalidTrappedMessage);
-                };
-                let message = ParachainAppCall::ReportXCMTransferResult {
-                    message_id,
-                    transfer_status:
-                        bridge_types::substrate::XCMAppTransferStatus::XCMTransferError,
-                };
-                let mes_bytes = message.prepare_message();
-                Self::deposit_event(Event::<T>::TrappedMessageRefundSent(
-                    message_id, recipient, asset_id, amount,
-                ));
-                mes_bytes
-            } else {
-                // otherwise - send assets to bridge
-                let message = ParachainAppCall::Transfer {
-                    asset_id,
-                    recipient: T::AccountIdConverter::convert(recipient.clone()),
-                    sender: None,
-                    amount,
-                };
-                let mes_bytes = message.prepare_message();
-                Self::deposit_event(Event::<T>::TrappedMessageSent(recipient, asset_id, amount));
-                mes_bytes
-            };
-
-            <T as Config>::OutboundChannel::submit(
-                SubNetworkId::Mainnet,
-                &raw_origin,
-                &message_bytes,
-                (),
-            )?;
-            BridgeAssetTrap::<T>::remove(nonce);
+            Self::do_claim_bridge_asset(nonce)?;
             Ok(().into())
         }
 
@@ -383,10 +792,13 @@ pub mod pallet {
             minimal_xcm_amount: u128,
         ) -> DispatchResultWithPostInfo {
             let _ = T::CallOrigin::ensure_origin(origin)?;
-            let Some(multilocation) = Self::get_multilocation_from_asset_id(asset_id) else {
+            if let Some(multilocation) = Self::get_multilocation_from_asset_id(asset_id) {
+                AssetMinimumAmount::<T>::set(multilocation, Some(minimal_xcm_amount));
+            } else if let Some(abstract_id) = Self::get_abstract_from_asset_id(asset_id) {
+                AbstractAssetMinimumAmount::<T>::set(abstract_id, Some(minimal_xcm_amount));
+            } else {
                 fail!(Error::<T>::MappingNotExist);
-            };
-            AssetMinimumAmount::<T>::set(multilocation, Some(minimal_xcm_amount));
+            }
             Self::deposit_event(Event::<T>::AssetMinimumAmountSet(asset_id, minimal_xcm_amount));
             Ok(().into())
         }
@@ -404,148 +816,1071 @@ pub mod pallet {
         }
 
         #[pallet::call_index(5)]
-        #[pallet::weight(<T as Config>::WeightInfo::sudo_send_xcm())]
+        #[pallet::weight(<T as Config>::WeightInfo::send_to_mainnet())]
         pub fn send_xor_to_mainnet(
             origin: OriginFor<T>,
             recipient: T::AccountId,
             amount: u128,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
-            T::Currency::withdraw(
-                &who,
-                amount,
-                WithdrawReasons::TRANSFER,
-                ExistenceRequirement::AllowDeath,
-            )?;
-            Self::add_to_channel(recipient, T::XorAssetId::get(), amount)?;
+            Self::do_send_to_mainnet(who, T::XorAssetId::get(), recipient, amount)?;
             Ok(().into())
         }
-    }
 
-    impl<T: Config> Pallet<T> {
-        pub fn add_to_channel(
-            account_id: T::AccountId,
+        /// Try to resubmit a message trapped by bridge. Can be called by any signed account,
+        /// the message is sent on behalf of the recipient stored in the trap
+        #[pallet::call_index(6)]
+        #[pallet::weight(<T as Config>::WeightInfo::try_claim_bridge_asset())]
+        pub fn claim_bridge_asset(origin: OriginFor<T>, nonce: u128) -> DispatchResultWithPostInfo {
+            let _ = ensure_signed(origin)?;
+            Self::do_claim_bridge_asset(nonce)?;
+            Ok(().into())
+        }
+
+        /// Try to resend a successful transfer report which could not be submitted
+        /// to the outbound channel. Can be called by root or any signed account
+        #[pallet::call_index(7)]
+        #[pallet::weight(<T as Config>::WeightInfo::resend_trapped_done_result())]
+        pub fn resend_trapped_done_result(
+            origin: OriginFor<T>,
+            message_id: H256,
+        ) -> DispatchResultWithPostInfo {
+            let _ = frame_system::ensure_signed_or_root(origin)?;
+            Self::do_resend_trapped_done_result(message_id)?;
+            Ok(().into())
+        }
+
+        /// Set or remove (if `limit` is None) limits of bridged transfers for an asset
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_asset_transfer_limit())]
+        pub fn set_asset_transfer_limit(
+            origin: OriginFor<T>,
             asset_id: AssetId,
-            amount: u128,
-        ) -> sp_runtime::DispatchResult {
-            let raw_origin = Some(account_id.clone()).into();
-            let xcm_mes = ParachainAppCall::Transfer {
-                asset_id,
-                recipient: T::AccountIdConverter::convert(account_id.clone()),
-                sender: None,
-                amount,
-            };
-            let xcm_mes_bytes = xcm_mes.clone().prepare_message();
-            if let Err(e) = <T as Config>::OutboundChannel::submit(
-                SubNetworkId::Mainnet,
-                &raw_origin,
-                &xcm_mes_bytes,
-                (),
-            ) {
-                Self::deposit_event(Event::<T>::SubmittingToChannelError(e, asset_id));
-                Self::trap_asset(None, asset_id, account_id, amount, false);
+            limit: Option<TransferLimit<BlockNumberFor<T>>>,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_manage_origin(origin)?;
+            if let Some(limit) = limit {
+                ensure!(
+                    limit.max_period_volume.is_none() || !limit.period.is_zero(),
+                    Error::<T>::InvalidTransferLimit
+                );
+                AssetTransferLimit::<T>::insert(asset_id, limit);
             } else {
-                Self::deposit_event(Event::<T>::AssetAddedToChannel(xcm_mes));
+                AssetTransferLimit::<T>::remove(asset_id);
+                AssetTransferVolume::<T>::remove(asset_id);
             }
-            Ok(())
+            Self::deposit_event(Event::<T>::AssetTransferLimitSet(asset_id, limit));
+            Ok(().into())
         }
 
-        pub fn do_transfer(
-            origin_output: CallOriginOutput<SubNetworkId, H256, ()>,
-            asset_id: AssetId,
-            sender: T::AccountId,
-            recipient: xcm::VersionedMultiLocation,
-            amount: u128,
-        ) {
-            frame_support::log::info!(
-                "Call transfer with params: {:?} by {:?}",
-                (asset_id, sender.clone(), recipient.clone(), amount),
-                origin_output
-            );
-            match Self::xcm_transfer_asset(asset_id, sender.clone(), recipient, amount) {
-                Ok(_) => {
-                    let message = ParachainAppCall::ReportXCMTransferResult {
-                        message_id: origin_output.message_id,
-                        transfer_status: bridge_types::substrate::XCMAppTransferStatus::Success,
-                    };
-                    let xcm_mes_bytes = message.prepare_message();
-                    let raw_origin = Some(sender).into();
-                    if let Err(e) = <T as Config>::OutboundChannel::submit(
-                        SubNetworkId::Mainnet,
-                        &raw_origin,
-                        &xcm_mes_bytes,
-                        (),
-                    ) {
-                        Self::deposit_event(Event::<T>::SubmittingToChannelError(e, asset_id));
-                        TrappedDoneResult::<T>::insert(origin_output.message_id, ());
-                        Self::deposit_event(Event::<T>::DoneMessageTrapped(
-                            origin_output.message_id,
-                        ));
-                    }
-                },
-                Err(_) => {
-                    Self::refund(sender, asset_id, amount, origin_output.message_id);
-                },
+        /// Pause or unpause all transfers in the direction
+        #[pallet::call_index(9)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_direction_paused())]
+        pub fn set_direction_paused(
+            origin: OriginFor<T>,
+            direction: TransferDirection,
+            paused: bool,
+        ) -> DispatchResultWithPostInfo {
+            T::PauseOrigin::ensure_origin(origin)?;
+            if paused {
+                PausedDirections::<T>::insert(direction, ());
+            } else {
+                PausedDirections::<T>::remove(direction);
             }
+            Self::deposit_event(Event::<T>::DirectionPauseSet(direction, paused));
+            Ok(().into())
         }
 
-        pub fn xcm_transfer_asset(
+        /// Pause or unpause transfers of the asset in both directions
+        #[pallet::call_index(10)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_asset_paused())]
+        pub fn set_asset_paused(
+            origin: OriginFor<T>,
             asset_id: AssetId,
-            sender: T::AccountId,
-            recipient: xcm::VersionedMultiLocation,
-            amount: u128,
-        ) -> sp_runtime::DispatchResult {
-            let recipient = match recipient {
-                xcm::VersionedMultiLocation::V3(m) => m,
-                _ => fail!(Error::<T>::WrongXCMVersion),
-            };
-            if let Some(xcm::v3::Junction::AccountId32 { id: recipient, .. }) =
-                recipient.match_and_split(&T::SelfLocation::get())
-            {
-                ensure!(asset_id == T::XorAssetId::get(), Error::<T>::InvalidAssetId);
-                T::Currency::deposit_creating(
-                    &T::AccountIdConverter::convert_back(AccountId32::new(*recipient)),
-                    amount,
-                );
-            } else if let Err(e) = <T as Config>::XcmTransfer::transfer(
-                sender.clone(),
-                asset_id,
-                amount,
-                recipient,
-                xcm::v3::WeightLimit::Unlimited,
-            ) {
-                Self::deposit_event(Event::<T>::TrasferringAssetError(e, asset_id));
-                return Err(e)
+            paused: bool,
+        ) -> DispatchResultWithPostInfo {
+            T::PauseOrigin::ensure_origin(origin)?;
+            if paused {
+                PausedAssets::<T>::insert(asset_id, ());
+            } else {
+                PausedAssets::<T>::remove(asset_id);
             }
-
-            Self::deposit_event(Event::<T>::AssetTransferred(sender, recipient, asset_id, amount));
-            Ok(())
+            Self::deposit_event(Event::<T>::AssetPauseSet(asset_id, paused));
+            Ok(().into())
         }
 
-        /// Perform refund if XCM transfer returned an errror
-        pub fn refund(account_id: T::AccountId, asset_id: AssetId, amount: u128, message_id: H256) {
-            let raw_origin = Some(account_id.clone()).into();
-            let message = ParachainAppCall::ReportXCMTransferResult {
+        /// Change multilocation of the asset, the minimum amount is moved to the new multilocation
+        ///
+        /// - `asset_id`: asset id in Sora Network,
+        /// - `new_multilocation`: new XCM multilocation of an asset,
+        #[pallet::call_index(11)]
+        #[pallet::weight(<T as Config>::WeightInfo::change_asset_mapping())]
+        pub fn change_asset_mapping(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            new_multilocation: xcm::VersionedMultiLocation,
+        ) -> DispatchResultWithPostInfo {
+            T::ManageOrigin::ensure_origin(origin)?;
+            let new_multilocation = MultiLocation::try_from(new_multilocation)
+                .map_err(|_| Error::<T>::WrongXCMVersion)?;
+            Self::do_change_asset_mapping(asset_id, new_multilocation)
+        }
+
+        /// Change asset id of the multilocation
+        ///
+        /// - `multilocation`: XCM multilocation of an asset,
+        /// - `new_asset_id`: new asset id in Sora Network,
+        #[pallet::call_index(12)]
+        #[pallet::weight(<T as Config>::WeightInfo::change_multilocation_mapping())]
+        pub fn change_multilocation_mapping(
+            origin: OriginFor<T>,
+            multilocation: xcm::VersionedMultiLocation,
+            new_asset_id: AssetId,
+        ) -> DispatchResultWithPostInfo {
+            T::ManageOrigin::ensure_origin(origin)?;
+            let multilocation =
+                MultiLocation::try_from(multilocation).map_err(|_| Error::<T>::WrongXCMVersion)?;
+            Self::do_change_multilocation_mapping(multilocation, new_asset_id)
+        }
+
+        /// Delete mapping of the asset along with its minimum amount
+        ///
+        /// - `asset_id`: asset id in Sora Network,
+        #[pallet::call_index(13)]
+        #[pallet::weight(<T as Config>::WeightInfo::delete_mapping())]
+        pub fn delete_mapping(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+        ) -> DispatchResultWithPostInfo {
+            T::ManageOrigin::ensure_origin(origin)?;
+            Self::do_delete_mapping(asset_id)
+        }
+
+        /// Set or remove (if `weight_limit` is None) XCM weight limit for transfers to the parachain
+        ///
+        /// - `para_id`: id of the destination parachain,
+        /// - `weight_limit`: weight limit to buy execution on the destination
+        #[pallet::call_index(14)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_destination_weight_limit())]
+        pub fn set_destination_weight_limit(
+            origin: OriginFor<T>,
+            para_id: u32,
+            weight_limit: Option<Weight>,
+        ) -> DispatchResultWithPostInfo {
+            T::ManageOrigin::ensure_origin(origin)?;
+            if let Some(weight_limit) = weight_limit {
+                DestinationWeightLimit::<T>::insert(para_id, weight_limit);
+            } else {
+                DestinationWeightLimit::<T>::remove(para_id);
+            }
+            Self::deposit_event(Event::<T>::DestinationWeightLimitSet(para_id, weight_limit));
+            Ok(().into())
+        }
+
+        /// Set or remove (if `fee` is None) fee for transfers to the parachain
+        ///
+        /// - `para_id`: id of the destination parachain,
+        /// - `fee`: asset and amount the fee is paid in
+        #[pallet::call_index(15)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_destination_fee())]
+        pub fn set_destination_fee(
+            origin: OriginFor<T>,
+            para_id: u32,
+            fee: Option<DestinationFee>,
+        ) -> DispatchResultWithPostInfo {
+            T::ManageOrigin::ensure_origin(origin)?;
+            if let Some(fee) = fee {
+                DestinationFees::<T>::insert(para_id, fee);
+            } else {
+                DestinationFees::<T>::remove(para_id);
+            }
+            Self::deposit_event(Event::<T>::DestinationFeeSet(para_id, fee));
+            Ok(().into())
+        }
+
+        /// Send Sora native asset held by the signer to the account on Sora
+        ///
+        /// - `asset_id`: asset id in Sora Network,
+        /// - `recipient`: account on Sora,
+        /// - `amount`: amount to send
+        #[pallet::call_index(16)]
+        #[pallet::weight(<T as Config>::WeightInfo::send_to_mainnet())]
+        pub fn send_to_mainnet(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            recipient: T::AccountId,
+            amount: u128,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::do_send_to_mainnet(who, asset_id, recipient, amount)?;
+            Ok(().into())
+        }
+
+        /// Mark or unmark the asset as native to Sora, which allows local accounts to hold it.
+        /// The asset can not be unmarked while it is held by local accounts
+        ///
+        /// - `asset_id`: asset id in Sora Network,
+        /// - `is_native`: whether the asset is native to Sora
+        #[pallet::call_index(17)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_sora_native_asset())]
+        pub fn set_sora_native_asset(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            is_native: bool,
+        ) -> DispatchResultWithPostInfo {
+            T::ManageOrigin::ensure_origin(origin)?;
+            if is_native {
+                SoraNativeAssets::<T>::insert(asset_id, ());
+            } else {
+                ensure!(
+                    T::LocalAssets::total_issuance(asset_id).is_zero(),
+                    Error::<T>::LocalAssetInUse
+                );
+                SoraNativeAssets::<T>::remove(asset_id);
+            }
+            Self::deposit_event(Event::<T>::SoraNativeAssetSet(asset_id, is_native));
+            Ok(().into())
+        }
+
+        /// Set or remove (if `metadata` is None) metadata of the registered asset
+        ///
+        /// - `asset_id`: asset id in Sora Network,
+        /// - `metadata`: name, symbol, decimals and existential deposit of the asset
+        #[pallet::call_index(18)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_asset_metadata())]
+        pub fn set_asset_metadata(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            metadata: Option<AssetMetadata>,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_manage_origin(origin)?;
+            Self::do_set_asset_metadata(asset_id, metadata)
+        }
+
+        /// Send trapped dust of inbound transfers of the asset to the recipient on Sora.
+        /// Only the part which makes up whole units on Sora is sent, the rest stays trapped
+        ///
+        /// - `asset_id`: asset id in Sora Network,
+        /// - `recipient`: account on Sora which receives the dust
+        #[pallet::call_index(19)]
+        #[pallet::weight(<T as Config>::WeightInfo::claim_asset_dust())]
+        pub fn claim_asset_dust(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            recipient: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_manage_origin(origin)?;
+            Self::ensure_not_paused(TransferDirection::Inbound, asset_id)?;
+            let (amount, dust) = Self::to_sora_amount(asset_id, AssetDust::<T>::get(asset_id))?;
+            ensure!(!amount.is_zero(), Error::<T>::AmountTooSmall);
+            AssetDust::<T>::insert(asset_id, dust);
+            Self::add_to_channel(recipient.clone(), asset_id, amount)?;
+            Self::deposit_event(Event::<T>::DustClaimed(recipient, asset_id, amount));
+            Ok(().into())
+        }
+
+        /// Set or remove (if `fee_per_second` is None) fee charged by XCM trader for execution
+        /// paid in the asset. Assets without fee can not pay for execution
+        ///
+        /// - `asset_id`: asset id in Sora Network,
+        /// - `fee_per_second`: amount of the asset charged for a second of execution weight
+        #[pallet::call_index(20)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_asset_fee_per_second())]
+        pub fn set_asset_fee_per_second(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            fee_per_second: Option<u128>,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_manage_origin(origin)?;
+            let Some(multilocation) = Self::get_multilocation_from_asset_id(asset_id) else {
+                ensure!(
+                    !AssetIdToAbstract::<T>::contains_key(asset_id),
+                    Error::<T>::UnsupportedAssetIdKind
+                );
+                fail!(Error::<T>::MappingNotExist);
+            };
+            AssetFeePerSecond::<T>::set(multilocation, fee_per_second);
+            Self::deposit_event(Event::<T>::AssetFeePerSecondSet(asset_id, fee_per_second));
+            Ok(().into())
+        }
+
+        /// Add or remove the location of the relay chain or a sibling parachain
+        /// to trusted XCM origins, which are allowed to execute paid XCM on the parachain
+        ///
+        /// - `location`: XCM location of the origin,
+        /// - `trusted`: add the location if true, remove it otherwise
+        #[pallet::call_index(21)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_trusted_xcm_origin())]
+        pub fn set_trusted_xcm_origin(
+            origin: OriginFor<T>,
+            location: xcm::VersionedMultiLocation,
+            trusted: bool,
+        ) -> DispatchResultWithPostInfo {
+            T::ManageOrigin::ensure_origin(origin)?;
+            let location =
+                MultiLocation::try_from(location).map_err(|_| Error::<T>::WrongXCMVersion)?;
+            if trusted {
+                TrustedXcmOrigins::<T>::insert(location, ());
+            } else {
+                TrustedXcmOrigins::<T>::remove(location);
+            }
+            Self::deposit_event(Event::<T>::TrustedXcmOriginSet(location, trusted));
+            Ok(().into())
+        }
+
+        /// Allow or disallow the relay chain or a sibling parachain to dispatch the call
+        /// with XCM `Transact`. The call should also pass the runtime XCM `SafeCallFilter`
+        ///
+        /// - `location`: XCM location of the origin,
+        /// - `call_index`: indices of the pallet and the call in the runtime,
+        /// - `allowed`: allow the call if true, disallow it otherwise
+        #[pallet::call_index(22)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_transact_call_allowed())]
+        pub fn set_transact_call_allowed(
+            origin: OriginFor<T>,
+            location: xcm::VersionedMultiLocation,
+            call_index: (u8, u8),
+            allowed: bool,
+        ) -> DispatchResultWithPostInfo {
+            T::ManageOrigin::ensure_origin(origin)?;
+            let location =
+                MultiLocation::try_from(location).map_err(|_| Error::<T>::WrongXCMVersion)?;
+            if allowed {
+                TransactAllowedCalls::<T>::insert(location, call_index, ());
+            } else {
+                TransactAllowedCalls::<T>::remove(location, call_index);
+            }
+            Self::deposit_event(Event::<T>::TransactCallAllowedSet(location, call_index, allowed));
+            Ok(().into())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Withdraws the asset from the local account and sends it to the account on Sora.
+        /// XOR is withdrawn from Currency, other Sora native assets from LocalAssets
+        pub fn do_send_to_mainnet(
+            who: T::AccountId,
+            asset_id: AssetId,
+            recipient: T::AccountId,
+            amount: u128,
+        ) -> sp_runtime::DispatchResult {
+            Self::ensure_not_paused(TransferDirection::Inbound, asset_id)?;
+            Self::ensure_transfer_allowed(asset_id, amount)?;
+            if asset_id == T::XorAssetId::get() {
+                T::Currency::withdraw(
+                    &who,
+                    amount,
+                    WithdrawReasons::TRANSFER,
+                    ExistenceRequirement::AllowDeath,
+                )?;
+            } else {
+                ensure!(
+                    SoraNativeAssets::<T>::contains_key(asset_id),
+                    Error::<T>::AssetNotSoraNative
+                );
+                T::LocalAssets::withdraw(asset_id, &who, amount)?;
+            }
+            Self::return_to_sora(T::DefaultSubNetworkId::get(), recipient, asset_id, amount)?;
+            Self::note_transfer(asset_id, amount);
+            Ok(())
+        }
+
+        /// Sends the asset received from other chains through XCM to Sora
+        pub fn add_to_channel(
+            account_id: T::AccountId,
+            asset_id: AssetId,
+            amount: u128,
+        ) -> sp_runtime::DispatchResult {
+            Accounting::<T>::mutate(asset_id, |accounting| {
+                accounting.deposited = accounting.deposited.saturating_add(amount)
+            });
+            Self::submit_transfer(T::DefaultSubNetworkId::get(), account_id, asset_id, amount)
+        }
+
+        /// Sends the asset back to Sora network, which has not been received through XCM
+        fn return_to_sora(
+            network_id: SubNetworkId,
+            account_id: T::AccountId,
+            asset_id: AssetId,
+            amount: u128,
+        ) -> sp_runtime::DispatchResult {
+            Accounting::<T>::mutate(asset_id, |accounting| {
+                accounting.returned = accounting.returned.saturating_add(amount)
+            });
+            Self::submit_transfer(network_id, account_id, asset_id, amount)
+        }
+
+        /// Submits transfer to the bridge, traps the asset if the channel is not available
+        fn submit_transfer(
+            network_id: SubNetworkId,
+            account_id: T::AccountId,
+            asset_id: AssetId,
+            amount: u128,
+        ) -> sp_runtime::DispatchResult {
+            let raw_origin = Some(account_id.clone()).into();
+            let xcm_mes = ParachainAppCall::Transfer {
+                asset_id,
+                recipient: T::AccountIdConverter::convert(account_id.clone()),
+                sender: None,
+                amount,
+            };
+            let xcm_mes_bytes = xcm_mes.clone().prepare_message();
+            if let Err(e) =
+                <T as Config>::OutboundChannel::submit(network_id, &raw_origin, &xcm_mes_bytes, ())
+            {
+                Self::deposit_event(Event::<T>::SubmittingToChannelError(e, asset_id));
+                Self::trap_asset(network_id, None, asset_id, account_id, amount, false);
+            } else {
+                Self::note_forwarded(asset_id, amount);
+                Self::deposit_event(Event::<T>::AssetAddedToChannel(xcm_mes));
+            }
+            Ok(())
+        }
+
+        pub fn do_transfer(
+            origin_output: CallOriginOutput<SubNetworkId, H256, ()>,
+            asset_id: AssetId,
+            sender: T::AccountId,
+            recipient: xcm::VersionedMultiLocation,
+            amount: u128,
+        ) {
+            frame_support::log::info!(
+                "Call transfer with params: {:?} by {:?}",
+                (asset_id, sender.clone(), recipient.clone(), amount),
+                origin_output
+            );
+            match Self::ensure_not_paused(TransferDirection::Outbound, asset_id)
+                .and_then(|_| Self::ensure_transfer_allowed(asset_id, amount))
+                .and_then(|_| {
+                    Self::xcm_transfer_asset(
+                        origin_output.network_id,
+                        asset_id,
+                        sender.clone(),
+                        recipient,
+                        amount,
+                    )
+                }) {
+                Ok(_) => {
+                    Self::note_transfer(asset_id, amount);
+                    let message = ParachainAppCall::ReportXCMTransferResult {
+                        message_id: origin_output.message_id,
+                        transfer_status: bridge_types::substrate::XCMAppTransferStatus::Success,
+                    };
+                    let xcm_mes_bytes = message.prepare_message();
+                    let raw_origin = Some(sender.clone()).into();
+                    if let Err(e) = <T as Config>::OutboundChannel::submit(
+                        origin_output.network_id,
+                        &raw_origin,
+                        &xcm_mes_bytes,
+                        (),
+                    ) {
+                        Self::deposit_event(Event::<T>::SubmittingToChannelError(e, asset_id));
+                        TrappedDoneResult::<T>::insert(
+                            origin_output.message_id,
+                            TrappedDoneResultInfo {
+                                network_id: origin_output.network_id,
+                                sender: Some(sender),
+                            },
+                        );
+                        Self::deposit_event(Event::<T>::DoneMessageTrapped(
+                            origin_output.message_id,
+                        ));
+                    }
+                },
+                Err(_) => {
+                    Self::refund(
+                        origin_output.network_id,
+                        sender,
+                        asset_id,
+                        amount,
+                        origin_output.message_id,
+                    );
+                },
+            }
+        }
+
+        /// Sends the asset received from Sora network `network_id` to the recipient
+        pub fn xcm_transfer_asset(
+            network_id: SubNetworkId,
+            asset_id: AssetId,
+            sender: T::AccountId,
+            recipient: xcm::VersionedMultiLocation,
+            amount: u128,
+        ) -> sp_runtime::DispatchResult {
+            let recipient = xcm::v3::MultiLocation::try_from(recipient)
+                .map_err(|_| Error::<T>::WrongXCMVersion)?;
+            if let Some(xcm::v3::Junction::AccountId32 { id: recipient, .. }) =
+                recipient.match_and_split(&T::SelfLocation::get())
+            {
+                let account = T::AccountIdConverter::convert_back(AccountId32::new(*recipient));
+                if asset_id == T::XorAssetId::get() {
+                    // deposit to a new account below existential deposit is dropped
+                    let credited = T::Currency::deposit_creating(&account, amount).peek();
+                    ensure!(credited == amount, Error::<T>::BelowExistentialDeposit);
+                } else {
+                    ensure!(
+                        SoraNativeAssets::<T>::contains_key(asset_id),
+                        Error::<T>::InvalidAssetId
+                    );
+                    T::LocalAssets::deposit(asset_id, &account, amount)?;
+                }
+                Self::deposit_event(Event::<T>::AssetDeposited(account, asset_id, amount));
+            } else if AssetIdToAbstract::<T>::contains_key(asset_id) {
+                // abstract assets have no location to be sent to
+                fail!(Error::<T>::UnsupportedAssetIdKind);
+            } else {
+                let (foreign_amount, dust) = Self::to_foreign_amount(asset_id, amount)?;
+                // storage layer reverts the withdrawn amount and the dust refund
+                // if the transfer fails, so the refund of the whole amount does not duplicate them
+                let result = frame_support::storage::with_storage_layer(|| {
+                    Self::send_with_fee(sender.clone(), asset_id, foreign_amount, recipient)?;
+                    if dust > 0 {
+                        // dust can not be sent to the foreign chain, so it is sent back to the sender
+                        Self::return_to_sora(network_id, sender.clone(), asset_id, dust)?;
+                        Self::deposit_event(Event::<T>::DustRefunded(
+                            sender.clone(),
+                            asset_id,
+                            dust,
+                        ));
+                    }
+                    Ok(())
+                });
+                if let Err(e) = result {
+                    Self::deposit_event(Event::<T>::TrasferringAssetError(e, asset_id));
+                    return Err(e)
+                }
+            }
+
+            Self::deposit_event(Event::<T>::AssetTransferred(sender, recipient, asset_id, amount));
+            Ok(())
+        }
+
+        /// Perform refund if XCM transfer returned an errror
+        pub fn refund(
+            network_id: SubNetworkId,
+            account_id: T::AccountId,
+            asset_id: AssetId,
+            amount: u128,
+            message_id: H256,
+        ) {
+            let raw_origin = Some(account_id.clone()).into();
+            let message = ParachainAppCall::ReportXCMTransferResult {
                 message_id,
                 transfer_status: bridge_types::substrate::XCMAppTransferStatus::XCMTransferError,
             };
             let xcm_mes_bytes = message.prepare_message();
-            if let Err(e) = <T as Config>::OutboundChannel::submit(
-                SubNetworkId::Mainnet,
-                &raw_origin,
-                &xcm_mes_bytes,
-                (),
-            ) {
+            if let Err(e) =
+                <T as Config>::OutboundChannel::submit(network_id, &raw_origin, &xcm_mes_bytes, ())
+            {
                 Self::deposit_event(Event::<T>::SubmittingToChannelError(e, asset_id));
-                Self::trap_asset(Some(message_id), asset_id, account_id.clone(), amount, true);
+                Self::trap_asset(
+                    network_id,
+                    Some(message_id),
+                    asset_id,
+                    account_id.clone(),
+                    amount,
+                    true,
+                );
             }
             Self::deposit_event(Event::<T>::AssetRefundSent(
                 message_id, account_id, asset_id, amount,
             ));
         }
 
+        /// Resubmit a trapped message to the outbound channel and remove it from the trap
+        pub fn do_claim_bridge_asset(nonce: u128) -> sp_runtime::DispatchResult {
+            let Some(TrappedMessage {
+                network_id,
+                asset_id,
+                recipient,
+                amount,
+                message_id,
+                is_refund,
+            }) = Self::bridge_asset_trap(nonce) else {
+                fail!(Error::<T>::TrappedMessageNotFound)
+            };
+            // refunds only report failed outbound transfers, so they are not paused
+            if !is_refund {
+                Self::ensure_not_paused(TransferDirection::Inbound, asset_id)?;
+            }
+            let raw_origin = Some(recipient.clone()).into();
+            let message_bytes = if is_refund {
+                // if we need to refund - send message to report that errror has occured
+                let Some(message_id) = message_id else {
+                    fail!(Error::<T>::InvalidTrappedMessage);
+                };
+                let message = ParachainAppCall::ReportXCMTransferResult {
+                    message_id,
+                    transfer_status:
+                        bridge_types::substrate::XCMAppTransferStatus::XCMTransferError,
+                };
+                let mes_bytes = message.prepare_message();
+                Self::deposit_event(Event::<T>::TrappedMessageRefundSent(
+                    message_id, recipient, asset_id, amount,
+                ));
+                mes_bytes
+            } else {
+                // otherwise - send assets to bridge
+                let message = ParachainAppCall::Transfer {
+                    asset_id,
+                    recipient: T::AccountIdConverter::convert(recipient.clone()),
+                    sender: None,
+                    amount,
+                };
+                let mes_bytes = message.prepare_message();
+                Self::deposit_event(Event::<T>::TrappedMessageSent(recipient, asset_id, amount));
+                mes_bytes
+            };
+
+            <T as Config>::OutboundChannel::submit(network_id, &raw_origin, &message_bytes, ())?;
+            if !is_refund {
+                Self::note_forwarded(asset_id, amount);
+            }
+            BridgeAssetTrap::<T>::remove(nonce);
+            Ok(())
+        }
+
+        /// Adds `amount` to the asset amount forwarded to the bridge
+        fn note_forwarded(asset_id: AssetId, amount: u128) {
+            Accounting::<T>::mutate(asset_id, |accounting| {
+                accounting.forwarded = accounting.forwarded.saturating_add(amount)
+            });
+        }
+
+        /// Checks that in-flight amount of each asset equals to the amount trapped by bridge
+        /// and that only the amounts to be sent to Sora have been forwarded
+        #[cfg(any(feature = "try-runtime", test))]
+        pub fn do_try_state() -> Result<(), &'static str> {
+            let mut trapped = scale_info::prelude::collections::BTreeMap::<AssetId, u128>::new();
+            for (_, message) in BridgeAssetTrap::<T>::iter() {
+                if !message.is_refund {
+                    let amount = trapped.entry(message.asset_id).or_default();
+                    *amount = amount.saturating_add(message.amount);
+                }
+            }
+            for (asset_id, accounting) in Accounting::<T>::iter() {
+                ensure!(
+                    accounting.forwarded <=
+                        accounting.deposited.saturating_add(accounting.returned),
+                    "Forwarded amount exceeds deposited and returned amount"
+                );
+                ensure!(
+                    accounting.in_flight() == trapped.remove(&asset_id).unwrap_or_default(),
+                    "In-flight amount does not match trapped amount"
+                );
+            }
+            ensure!(trapped.is_empty(), "Trapped asset has no accounting");
+            Ok(())
+        }
+
+        /// Resubmit a trapped successful transfer report and remove it from the trap
+        pub fn do_resend_trapped_done_result(message_id: H256) -> sp_runtime::DispatchResult {
+            let Some(info) = TrappedDoneResult::<T>::get(message_id) else {
+                fail!(Error::<T>::TrappedDoneResultNotFound)
+            };
+            let message = ParachainAppCall::ReportXCMTransferResult {
+                message_id,
+                transfer_status: bridge_types::substrate::XCMAppTransferStatus::Success,
+            };
+            // results trapped before senders have been stored are sent on behalf of the parachain
+            let raw_origin = info.sender.map_or(RawOrigin::Root, RawOrigin::Signed);
+            <T as Config>::OutboundChannel::submit(
+                info.network_id,
+                &raw_origin,
+                &message.prepare_message(),
+                (),
+            )?;
+            TrappedDoneResult::<T>::remove(message_id);
+            Self::deposit_event(Event::<T>::TrappedDoneResultSent(message_id));
+            Ok(())
+        }
+
+        /// Resends trapped done results while weight and retries limit allow it,
+        /// starting after the result the previous retries stopped at.
+        /// Returns false if there is no weight left or the outbound channel rejected a message
+        fn retry_trapped_done_results(meter: &mut WeightMeter, retries: &mut u32) -> bool {
+            // nothing is charged if there is nothing to retry
+            if TrappedDoneResult::<T>::iter_keys().next().is_none() {
+                return true
+            }
+            let retry_weight = <T as Config>::WeightInfo::resend_trapped_done_result();
+            let count = Self::affordable_retries(meter, retry_weight, *retries);
+            if count == 0 {
+                return false
+            }
+            let cursor = TrappedDoneResultRetryCursor::<T>::get();
+            let message_ids = match cursor {
+                Some(message_id) => TrappedDoneResult::<T>::iter_keys_from(
+                    TrappedDoneResult::<T>::hashed_key_for(message_id),
+                ),
+                None => TrappedDoneResult::<T>::iter_keys(),
+            }
+            .take(count as usize)
+            .collect::<Vec<_>>();
+            // reads of the cursor and the found keys are covered by affordable_retries
+            if !meter.check_accrue(T::DbWeight::get().reads(message_ids.len() as u64 + 1)) {
+                return false
+            }
+            // the end of the map is reached if less keys than requested are found
+            let reached_end = message_ids.len() < count as usize;
+            let mut next_cursor = cursor;
+            let mut completed = true;
+            for message_id in message_ids {
+                if !meter.check_accrue(retry_weight) {
+                    completed = false;
+                    break
+                }
+                *retries = retries.saturating_sub(1);
+                next_cursor = Some(message_id);
+                // storage layer is needed to revert changes of failed submission
+                if frame_support::storage::with_storage_layer(|| {
+                    Self::do_resend_trapped_done_result(message_id)
+                })
+                .is_err()
+                {
+                    completed = false;
+                    break
+                }
+            }
+            if completed && reached_end {
+                next_cursor = None;
+            }
+            if next_cursor != cursor && meter.check_accrue(T::DbWeight::get().writes(1)) {
+                TrappedDoneResultRetryCursor::<T>::set(next_cursor);
+            }
+            completed
+        }
+
+        /// Resubmits messages trapped in BridgeAssetTrap while weight and retries limit allow it,
+        /// starting after the message the previous retries stopped at.
+        /// Paused messages are skipped and stay trapped.
+        /// Stops at the first other failure, because the outbound channel is most likely still full
+        fn retry_bridge_asset_trap(meter: &mut WeightMeter, retries: &mut u32) {
+            // nothing is charged if there is nothing to retry
+            if BridgeAssetTrap::<T>::iter_keys().next().is_none() {
+                return
+            }
+            let retry_weight = <T as Config>::WeightInfo::try_claim_bridge_asset();
+            let count = Self::affordable_retries(meter, retry_weight, *retries);
+            if count == 0 {
+                return
+            }
+            let cursor = BridgeAssetTrapRetryCursor::<T>::get();
+            let nonces = match cursor {
+                Some(nonce) => BridgeAssetTrap::<T>::iter_keys_from(
+                    BridgeAssetTrap::<T>::hashed_key_for(nonce),
+                ),
+                None => BridgeAssetTrap::<T>::iter_keys(),
+            }
+            .take(count as usize)
+            .collect::<Vec<_>>();
+            // reads of the cursor and the found keys are covered by affordable_retries
+            if !meter.check_accrue(T::DbWeight::get().reads(nonces.len() as u64 + 1)) {
+                return
+            }
+            // the end of the map is reached if less keys than requested are found
+            let reached_end = nonces.len() < count as usize;
+            let mut next_cursor = cursor;
+            let mut completed = true;
+            for nonce in nonces {
+                if !meter.check_accrue(retry_weight) {
+                    completed = false;
+                    break
+                }
+                *retries = retries.saturating_sub(1);
+                next_cursor = Some(nonce);
+                // storage layer is needed to revert events of failed submission
+                match frame_support::storage::with_storage_layer(|| {
+                    Self::do_claim_bridge_asset(nonce)
+                }) {
+                    Ok(()) => Self::deposit_event(Event::<T>::BridgeAssetTrapRetried(nonce)),
+                    Err(err)
+                        if err == Error::<T>::DirectionPaused.into() ||
+                            err == Error::<T>::AssetPaused.into() => {},
+                    Err(_) => {
+                        completed = false;
+                        break
+                    },
+                }
+            }
+            if completed && reached_end {
+                next_cursor = None;
+            }
+            if next_cursor != cursor && meter.check_accrue(T::DbWeight::get().writes(1)) {
+                BridgeAssetTrapRetryCursor::<T>::set(next_cursor);
+            }
+        }
+
+        /// Returns the number of retries the meter can cover,
+        /// including reads of the retried keys and an update of the retry cursor
+        fn affordable_retries(meter: &WeightMeter, retry_weight: Weight, retries: u32) -> u32 {
+            let item_weight = T::DbWeight::get().reads(1).saturating_add(retry_weight);
+            let mut count = retries;
+            while count > 0 &&
+                !meter.can_accrue(
+                    T::DbWeight::get()
+                        .reads_writes(1, 1)
+                        .saturating_add(item_weight.saturating_mul(count.into())),
+                )
+            {
+                count -= 1;
+            }
+            count
+        }
+
+        /// Sends the asset with XcmTransfer, the fee is paid according to the destination fee settings:
+        /// - if set in the transferred asset, the fee amount is deducted from the transferred amount,
+        /// - otherwise the fee is paid from the transferred amount.
+        /// Fee in another asset is not sent, because the parachain has no balance to pay it from
+        fn send_with_fee(
+            sender: T::AccountId,
+            asset_id: AssetId,
+            amount: u128,
+            recipient: xcm::v3::MultiLocation,
+        ) -> sp_runtime::DispatchResult {
+            let weight_limit = Self::weight_limit_for(&recipient);
+            let fee = Self::destination_para_id(&recipient).and_then(DestinationFees::<T>::get);
+            match fee {
+                Some(fee) if fee.asset_id == asset_id => {
+                    ensure!(fee.amount < amount, Error::<T>::FeeExceedsAmount);
+                    <T as Config>::XcmTransfer::transfer_with_fee(
+                        sender,
+                        asset_id,
+                        amount - fee.amount,
+                        fee.amount,
+                        recipient,
+                        weight_limit,
+                    )?;
+                },
+                _ => {
+                    <T as Config>::XcmTransfer::transfer(
+                        sender,
+                        asset_id,
+                        amount,
+                        recipient,
+                        weight_limit,
+                    )?;
+                },
+            }
+            Ok(())
+        }
+
+        /// Converts the amount of the asset in Sora decimals to the foreign decimals.
+        /// Returns converted amount and the dust in Sora decimals
+        pub fn to_foreign_amount(
+            asset_id: AssetId,
+            amount: u128,
+        ) -> Result<(u128, u128), sp_runtime::DispatchError> {
+            let decimals = Self::asset_decimals(asset_id).ok_or(Error::<T>::MetadataNotExist)?;
+            let (converted, dust) =
+                decimals.to_foreign(amount).ok_or(Error::<T>::AmountOverflow)?;
+            ensure!(converted > 0, Error::<T>::AmountTooSmall);
+            Ok((converted, dust))
+        }
+
+        /// Converts the amount of the asset in foreign decimals to Sora decimals.
+        /// Returns converted amount and the dust in foreign decimals
+        pub fn to_sora_amount(
+            asset_id: AssetId,
+            amount: u128,
+        ) -> Result<(u128, u128), sp_runtime::DispatchError> {
+            let decimals = Self::asset_decimals(asset_id).ok_or(Error::<T>::MetadataNotExist)?;
+            let (converted, dust) = decimals.to_sora(amount).ok_or(Error::<T>::AmountOverflow)?;
+            ensure!(converted > 0, Error::<T>::AmountTooSmall);
+            Ok((converted, dust))
+        }
+
+        /// Converts the withdrawn amount of the asset in foreign decimals to Sora decimals.
+        /// The remainder which can not be represented on Sora is covered by the trapped dust,
+        /// otherwise it is rounded up to a unit on Sora and the excess is added to the dust
+        pub fn to_sora_withdrawn_amount(
+            asset_id: AssetId,
+            amount: u128,
+        ) -> Result<u128, sp_runtime::DispatchError> {
+            let decimals = Self::asset_decimals(asset_id).ok_or(Error::<T>::MetadataNotExist)?;
+            let (converted, remainder) =
+                decimals.to_sora(amount).ok_or(Error::<T>::AmountOverflow)?;
+            if remainder.is_zero() {
+                return Ok(converted)
+            }
+            let dust = AssetDust::<T>::get(asset_id);
+            if dust >= remainder {
+                AssetDust::<T>::insert(asset_id, dust - remainder);
+                return Ok(converted)
+            }
+            let (unit, _) = decimals.to_foreign(1).ok_or(Error::<T>::AmountOverflow)?;
+            AssetDust::<T>::insert(asset_id, dust.saturating_add(unit - remainder));
+            Ok(converted.saturating_add(1))
+        }
+
+        /// Returns Sora and foreign decimals of the asset, None if the asset has no metadata
+        pub fn asset_decimals(asset_id: AssetId) -> Option<DecimalsPair> {
+            Metadata::<T>::get(asset_id).map(|metadata| DecimalsPair {
+                sora: T::SoraDecimals::get(),
+                foreign: metadata.decimals,
+            })
+        }
+
+        /// Returns fee for the execution `weight` paid in the asset at `multilocation`,
+        /// None if the fee is not set for the asset
+        pub fn fee_for_weight(multilocation: &MultiLocation, weight: Weight) -> Option<u128> {
+            let fee_per_second = AssetFeePerSecond::<T>::get(multilocation)?;
+            Some(
+                fee_per_second.saturating_mul(weight.ref_time().into()) /
+                    u128::from(frame_support::weights::constants::WEIGHT_REF_TIME_PER_SECOND),
+            )
+        }
+
+        /// Returns fee for the execution `weight` paid in the asset, used by runtime api
+        pub fn quote_fee(asset_id: AssetId, weight: Weight) -> Option<u128> {
+            let multilocation = AssetIdToMultilocation::<T>::get(asset_id)?;
+            Self::fee_for_weight(&multilocation, weight)
+        }
+
+        /// Keeps the dust of the inbound transfer on the parachain
+        pub fn trap_dust(asset_id: AssetId, dust: u128) {
+            AssetDust::<T>::mutate(asset_id, |total| *total = total.saturating_add(dust));
+            Self::deposit_event(Event::<T>::DustTrapped(asset_id, dust));
+        }
+
+        /// Returns id of the parachain the location belongs to.
+        /// Parachain id is taken from the first junction of the location relative to the relay chain
+        pub fn destination_para_id(location: &xcm::v3::MultiLocation) -> Option<u32> {
+            match location.interior.first() {
+                Some(xcm::v3::Junction::Parachain(para_id)) if location.parents == 1 =>
+                    Some(*para_id),
+                _ => None,
+            }
+        }
+
+        /// Returns XCM weight limit for transfers to the location
+        pub fn weight_limit_for(location: &xcm::v3::MultiLocation) -> xcm::v3::WeightLimit {
+            match Self::destination_para_id(location).and_then(DestinationWeightLimit::<T>::get) {
+                Some(weight) => xcm::v3::WeightLimit::Limited(weight),
+                None => xcm::v3::WeightLimit::Unlimited,
+            }
+        }
+
+        /// Ensures that origin is CallOrigin or ManageOrigin
+        pub fn ensure_manage_origin(origin: OriginFor<T>) -> sp_runtime::DispatchResult {
+            if let Err(origin) = T::CallOrigin::try_origin(origin) {
+                T::ManageOrigin::ensure_origin(origin)?;
+            }
+            Ok(())
+        }
+
+        /// Checks that no amounts of the asset are trapped on the parachain,
+        /// neither by the bridge nor as the dust of transfers
+        pub fn ensure_not_in_flight(asset_id: AssetId) -> sp_runtime::DispatchResult {
+            ensure!(
+                Accounting::<T>::get(asset_id).in_flight() == 0 &&
+                    AssetDust::<T>::get(asset_id) == 0,
+                Error::<T>::AssetInFlight
+            );
+            Ok(())
+        }
+
+        /// Checks that transfers of the asset in the direction are not paused
+        pub fn ensure_not_paused(
+            direction: TransferDirection,
+            asset_id: AssetId,
+        ) -> sp_runtime::DispatchResult {
+            ensure!(!PausedDirections::<T>::contains_key(direction), Error::<T>::DirectionPaused);
+            ensure!(!PausedAssets::<T>::contains_key(asset_id), Error::<T>::AssetPaused);
+            Ok(())
+        }
+
+        /// Returns the volume of the current period, shifting the stored volume to the previous
+        /// period or resetting it if the periods are over
+        fn period_volume(
+            asset_id: AssetId,
+            limit: &TransferLimit<BlockNumberFor<T>>,
+        ) -> TransferVolume<BlockNumberFor<T>> {
+            if limit.period.is_zero() {
+                return TransferVolume::default()
+            }
+            let now = frame_system::Pallet::<T>::block_number();
+            let period_start = now.saturating_sub(now % limit.period);
+            let volume = AssetTransferVolume::<T>::get(asset_id);
+            if volume.period_start == period_start {
+                volume
+            } else if volume.period_start.saturating_add(limit.period) == period_start {
+                TransferVolume { period_start, volume: 0, previous_volume: volume.volume }
+            } else {
+                TransferVolume { period_start, volume: 0, previous_volume: 0 }
+            }
+        }
+
+        /// Returns the approximate volume transferred during the last `period` blocks,
+        /// the previous period volume is weighted by its share still in the rolling window
+        fn rolling_volume(
+            volume: &TransferVolume<BlockNumberFor<T>>,
+            limit: &TransferLimit<BlockNumberFor<T>>,
+        ) -> u128 {
+            if limit.period.is_zero() {
+                return volume.volume
+            }
+            let now = frame_system::Pallet::<T>::block_number();
+            let elapsed = now.saturating_sub(volume.period_start).min(limit.period);
+            let share = Perbill::from_rational(
+                limit.period.saturating_sub(elapsed).saturated_into::<u64>(),
+                limit.period.saturated_into::<u64>(),
+            );
+            share.mul_ceil(volume.previous_volume).saturating_add(volume.volume)
+        }
+
+        /// Checks that transfer of `amount` does not exceed the asset transfer limit
+        pub fn ensure_transfer_allowed(
+            asset_id: AssetId,
+            amount: u128,
+        ) -> sp_runtime::DispatchResult {
+            let Some(limit) = AssetTransferLimit::<T>::get(asset_id) else {
+                return Ok(())
+            };
+            let volume = Self::period_volume(asset_id, &limit);
+            let exceeds_transfer_amount =
+                limit.max_transfer_amount.map_or(false, |max| amount > max);
+            let exceeds_period_volume = limit.max_period_volume.map_or(false, |max| {
+                Self::rolling_volume(&volume, &limit).saturating_add(amount) > max
+            });
+            if exceeds_transfer_amount || exceeds_period_volume {
+                Self::deposit_event(Event::<T>::TransferThrottled(asset_id, amount));
+                fail!(Error::<T>::TransferLimitExceeded);
+            }
+            Ok(())
+        }
+
+        /// Adds `amount` to the asset volume of the current period if the asset is limited
+        pub fn note_transfer(asset_id: AssetId, amount: u128) {
+            let Some(limit) = AssetTransferLimit::<T>::get(asset_id) else {
+                return
+            };
+            let mut volume = Self::period_volume(asset_id, &limit);
+            volume.volume = volume.volume.saturating_add(amount);
+            AssetTransferVolume::<T>::insert(asset_id, volume);
+        }
+
+        /// Returns trapped messages sorted by nonce, filtered and paginated, used by runtime api
+        pub fn trapped_messages(
+            filter: TrappedMessageFilter<T::AccountId>,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<(u128, TrappedMessage<T::AccountId>)> {
+            let mut messages = BridgeAssetTrap::<T>::iter()
+                .filter(|(_, message)| filter.matches(message))
+                .collect::<Vec<_>>();
+            messages.sort_by_key(|(nonce, _)| *nonce);
+            messages.into_iter().skip(offset as usize).take(limit as usize).collect()
+        }
+
+        /// Returns message ids of trapped successful transfer reports, used by runtime api
+        pub fn trapped_done_results() -> Vec<H256> {
+            TrappedDoneResult::<T>::iter_keys().collect()
+        }
+
         /// Stores tokes that had not been refunded is some reason like an error
         pub fn trap_asset(
+            network_id: SubNetworkId,
             message_id: Option<H256>,
             asset_id: AssetId,
             sender: T::AccountId,
@@ -559,6 +1894,7 @@ pub mod pallet {
             BridgeAssetTrap::<T>::insert(
                 nonce,
                 TrappedMessage {
+                    network_id,
                     asset_id,
                     recipient: sender.clone(),
                     message_id,
@@ -581,6 +1917,7 @@ pub mod pallet {
         ) -> DispatchResultWithPostInfo {
             ensure!(
                 AssetIdToMultilocation::<T>::get(asset_id).is_none() &&
+                    AssetIdToAbstract::<T>::get(asset_id).is_none() &&
                     MultilocationToAssetId::<T>::get(multilocation).is_none(),
                 Error::<T>::MappingAlreadyExists
             );
@@ -589,11 +1926,30 @@ pub mod pallet {
             Ok(().into())
         }
 
+        /// Perform registration for mapping of an AssetId <-> abstract XCM asset id
+        ///
+        /// - `asset_id`: asset id in Sora Network,
+        /// - `abstract_id`: XCM abstract id of an asset,
+        pub fn register_abstract_mapping(
+            asset_id: AssetId,
+            abstract_id: [u8; 32],
+        ) -> DispatchResultWithPostInfo {
+            ensure!(
+                AssetIdToMultilocation::<T>::get(asset_id).is_none() &&
+                    AssetIdToAbstract::<T>::get(asset_id).is_none() &&
+                    AbstractToAssetId::<T>::get(abstract_id).is_none(),
+                Error::<T>::MappingAlreadyExists
+            );
+            AssetIdToAbstract::<T>::insert(asset_id, abstract_id);
+            AbstractToAssetId::<T>::insert(abstract_id, asset_id);
+            Ok(().into())
+        }
+
         /// Perform change of mapping of an AssetId -> Multilocation
         ///
         /// - `asset_id`: asset id in Sora Network,
         /// - `new_multilocation`: new XCM multilocation of an asset,
-        pub fn change_asset_mapping(
+        pub fn do_change_asset_mapping(
             asset_id: AssetId,
             new_multilocation: MultiLocation,
         ) -> DispatchResultWithPostInfo {
@@ -611,6 +1967,14 @@ pub mod pallet {
                         // remove old multilocation
                         MultilocationToAssetId::<T>::remove(*ml);
 
+                        // minimum amount is stored by multilocation, so move it as well
+                        if let Some(minimum_amount) = AssetMinimumAmount::<T>::take(*ml) {
+                            AssetMinimumAmount::<T>::insert(new_multilocation, minimum_amount);
+                        }
+                        if let Some(fee_per_second) = AssetFeePerSecond::<T>::take(*ml) {
+                            AssetFeePerSecond::<T>::insert(new_multilocation, fee_per_second);
+                        }
+
                         *ml = new_multilocation;
                     },
                 }
@@ -624,7 +1988,7 @@ pub mod pallet {
         ///
         /// - `multilocation`: XCM multilocation of an asset,
         /// - `new_asset_id`: new asset id in Sora Network,
-        pub fn change_multilocation_mapping(
+        pub fn do_change_multilocation_mapping(
             multilocation: MultiLocation,
             new_asset_id: AssetId,
         ) -> DispatchResultWithPostInfo {
@@ -636,15 +2000,24 @@ pub mod pallet {
                         Some(asset_id) => {
                             // ensure that new_assetid mapping does not exist
                             ensure!(
-                                AssetIdToMultilocation::<T>::get(new_asset_id).is_none(),
+                                AssetIdToMultilocation::<T>::get(new_asset_id).is_none() &&
+                                    AssetIdToAbstract::<T>::get(new_asset_id).is_none(),
                                 Error::<T>::MappingAlreadyExists
                             );
 
+                            // trapped amounts and dust of the old asset id would be left without its mapping
+                            Self::ensure_not_in_flight(*asset_id)?;
+
                             AssetIdToMultilocation::<T>::insert(new_asset_id, multilocation);
 
                             // remove old assetid
                             AssetIdToMultilocation::<T>::remove(*asset_id);
 
+                            // decimals belong to the multilocation, so metadata is moved as well
+                            if let Some(metadata) = Metadata::<T>::take(*asset_id) {
+                                Metadata::<T>::insert(new_asset_id, metadata);
+                            }
+
                             *asset_id = new_asset_id;
                         },
                     };
@@ -661,15 +2034,45 @@ pub mod pallet {
         /// Perform delete of mapping of an AssetId -> Multilocation
         ///
         /// - `asset_id`: asset id in Sora Network,
-        pub fn delete_mapping(asset_id: AssetId) -> DispatchResultWithPostInfo {
-            match AssetIdToMultilocation::<T>::get(asset_id) {
-                None => fail!(Error::<T>::MappingNotExist),
-                Some(multilocation) => {
-                    AssetIdToMultilocation::<T>::remove(asset_id);
-                    MultilocationToAssetId::<T>::remove(multilocation);
-                    Self::deposit_event(Event::<T>::MappingDeleted(asset_id, multilocation));
-                },
-            };
+        pub fn do_delete_mapping(asset_id: AssetId) -> DispatchResultWithPostInfo {
+            Self::ensure_not_in_flight(asset_id)?;
+            if let Some(multilocation) = AssetIdToMultilocation::<T>::get(asset_id) {
+                AssetIdToMultilocation::<T>::remove(asset_id);
+                MultilocationToAssetId::<T>::remove(multilocation);
+                AssetMinimumAmount::<T>::remove(multilocation);
+                AssetFeePerSecond::<T>::remove(multilocation);
+                Self::deposit_event(Event::<T>::MappingDeleted(asset_id, multilocation));
+            } else if let Some(abstract_id) = AssetIdToAbstract::<T>::get(asset_id) {
+                AssetIdToAbstract::<T>::remove(asset_id);
+                AbstractToAssetId::<T>::remove(abstract_id);
+                AbstractAssetMinimumAmount::<T>::remove(abstract_id);
+                Self::deposit_event(Event::<T>::AbstractMappingDeleted(asset_id, abstract_id));
+            } else {
+                fail!(Error::<T>::MappingNotExist);
+            }
+            Metadata::<T>::remove(asset_id);
+            Ok(().into())
+        }
+
+        /// Perform set or remove (if `metadata` is None) of metadata of the registered asset
+        ///
+        /// - `asset_id`: asset id in Sora Network,
+        /// - `metadata`: name, symbol, decimals and existential deposit of the asset
+        pub fn do_set_asset_metadata(
+            asset_id: AssetId,
+            metadata: Option<AssetMetadata>,
+        ) -> DispatchResultWithPostInfo {
+            ensure!(
+                AssetIdToMultilocation::<T>::contains_key(asset_id) ||
+                    AssetIdToAbstract::<T>::contains_key(asset_id),
+                Error::<T>::MappingNotExist
+            );
+            if let Some(metadata) = metadata.clone() {
+                Metadata::<T>::insert(asset_id, metadata);
+            } else {
+                Metadata::<T>::remove(asset_id);
+            }
+            Self::deposit_event(Event::<T>::AssetMetadataSet(asset_id, metadata));
             Ok(().into())
         }
     }
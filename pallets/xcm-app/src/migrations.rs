@@ -0,0 +1,191 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::*;
+use frame_support::{
+    pallet_prelude::*,
+    traits::{OnRuntimeUpgrade, StorageVersion},
+};
+
+pub mod v0 {
+    use super::*;
+
+    /// Trapped message before network id has been added
+    #[derive(Clone, RuntimeDebug, Encode, Decode, PartialEq, Eq, scale_info::TypeInfo)]
+    pub struct TrappedMessage<AccountId> {
+        pub asset_id: AssetId,
+        pub recipient: AccountId,
+        pub message_id: Option<H256>,
+        pub amount: u128,
+        pub is_refund: bool,
+    }
+
+    #[frame_support::storage_alias]
+    pub type BridgeAssetTrap<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_256,
+        u128,
+        TrappedMessage<<T as frame_system::Config>::AccountId>,
+        OptionQuery,
+    >;
+}
+
+pub mod v1 {
+    use super::*;
+
+    /// Initializes asset accounting with the amounts trapped by bridge,
+    /// because these amounts have been deposited, but not forwarded yet.
+    /// Trapped messages do not store where the asset came from,
+    /// so they are all counted as received through XCM
+    pub struct InitializeAccounting<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for InitializeAccounting<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if StorageVersion::get::<Pallet<T>>() >= 1 {
+                frame_support::log::info!("xcm_app: accounting is already initialized, skipping");
+                return T::DbWeight::get().reads(1)
+            }
+
+            let mut reads = 1;
+            let mut writes = 1;
+            for (_, message) in v0::BridgeAssetTrap::<T>::iter() {
+                reads += 1;
+                if !message.is_refund {
+                    Accounting::<T>::mutate(message.asset_id, |accounting| {
+                        accounting.deposited = accounting.deposited.saturating_add(message.amount)
+                    });
+                    reads += 1;
+                    writes += 1;
+                }
+            }
+            StorageVersion::new(1).put::<Pallet<T>>();
+            frame_support::log::info!("xcm_app: accounting initialized");
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+            ensure!(StorageVersion::get::<Pallet<T>>() >= 1, "Wrong storage version");
+            Ok(())
+        }
+    }
+}
+
+pub mod v2 {
+    use super::*;
+
+    /// Adds Sora network id to trapped messages and trapped done results.
+    /// All the messages have been sent to the default network before,
+    /// senders of trapped done results have not been stored, so they are left unknown
+    pub struct AddNetworkId<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for AddNetworkId<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if StorageVersion::get::<Pallet<T>>() != 1 {
+                frame_support::log::info!("xcm_app: network id migration is not needed, skipping");
+                return T::DbWeight::get().reads(1)
+            }
+
+            let network_id = T::DefaultSubNetworkId::get();
+            let mut count = 0;
+            BridgeAssetTrap::<T>::translate::<v0::TrappedMessage<T::AccountId>, _>(|_, message| {
+                count += 1;
+                Some(TrappedMessage {
+                    network_id,
+                    asset_id: message.asset_id,
+                    recipient: message.recipient,
+                    message_id: message.message_id,
+                    amount: message.amount,
+                    is_refund: message.is_refund,
+                })
+            });
+            TrappedDoneResult::<T>::translate::<(), _>(|_, _| {
+                count += 1;
+                Some(TrappedDoneResultInfo { network_id, sender: None })
+            });
+            StorageVersion::new(2).put::<Pallet<T>>();
+            frame_support::log::info!("xcm_app: network id added to {} trapped messages", count);
+            T::DbWeight::get().reads_writes(count + 1, count + 1)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+            ensure!(StorageVersion::get::<Pallet<T>>() == 2, "Wrong storage version");
+            Pallet::<T>::do_try_state()
+        }
+    }
+}
+
+pub mod v3 {
+    use super::*;
+
+    /// Adds the relay chain and the sibling parachains listed in `Origins` to trusted XCM origins.
+    /// All origins have been allowed to execute paid XCM before, so `Origins` should list
+    /// the counterparties approved by governance, other origins should be added by governance later
+    pub struct TrustRelayChain<T, Origins>(PhantomData<(T, Origins)>);
+
+    impl<T: Config, Origins: Get<Vec<MultiLocation>>> OnRuntimeUpgrade for TrustRelayChain<T, Origins> {
+        fn on_runtime_upgrade() -> Weight {
+            if StorageVersion::get::<Pallet<T>>() != 2 {
+                frame_support::log::info!(
+                    "xcm_app: trusted XCM origins migration is not needed, skipping"
+                );
+                return T::DbWeight::get().reads(1)
+            }
+
+            let origins = Origins::get();
+            let origins_count = origins.len() as u64;
+            TrustedXcmOrigins::<T>::insert(MultiLocation::parent(), ());
+            for origin in origins {
+                TrustedXcmOrigins::<T>::insert(origin, ());
+            }
+            StorageVersion::new(3).put::<Pallet<T>>();
+            frame_support::log::info!(
+                "xcm_app: relay chain and {} origins added to trusted XCM origins",
+                origins_count
+            );
+            T::DbWeight::get().reads_writes(1, origins_count + 2)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+            ensure!(StorageVersion::get::<Pallet<T>>() == 3, "Wrong storage version");
+            ensure!(
+                TrustedXcmOrigins::<T>::contains_key(MultiLocation::parent()),
+                "Relay chain is not trusted"
+            );
+            ensure!(
+                Origins::get().iter().all(|origin| TrustedXcmOrigins::<T>::contains_key(origin)),
+                "Listed origin is not trusted"
+            );
+            Ok(())
+        }
+    }
+}
@@ -56,6 +56,7 @@ frame_support::construct_runtime!(
     {
         System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
         Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+        Tokens: orml_tokens::{Pallet, Storage, Event<T>},
         XCMApp: xcm_app::{Pallet, Call, Storage, Event<T>},
     }
 );
@@ -93,7 +94,7 @@ impl system::Config for Test {
 }
 
 parameter_types! {
-    pub const ExistentialDeposit: u128 = 0;
+    pub static ExistentialDeposit: u128 = 0;
 }
 
 impl pallet_balances::Config for Test {
@@ -108,11 +109,34 @@ impl pallet_balances::Config for Test {
     type ReserveIdentifier = ();
 }
 
+orml_traits::parameter_type_with_key! {
+    pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+        0
+    };
+}
+
+impl orml_tokens::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type Amount = i128;
+    type CurrencyId = AssetId;
+    type WeightInfo = ();
+    type ExistentialDeposits = ExistentialDeposits;
+    type CurrencyHooks = ();
+    type MaxLocks = ();
+    type MaxReserves = ();
+    type ReserveIdentifier = ();
+    type DustRemovalWhitelist = Everything;
+}
+
 pub const SELF_PARA_ID: u32 = 2011;
 
 parameter_types! {
     pub SelfLocation: MultiLocation = MultiLocation::parent().pushed_with_interior(Junction::Parachain(SELF_PARA_ID)).unwrap();
     pub XorAssetId: AssetId = AssetId::repeat_byte(2);
+    pub const MaxTrappedRetriesPerBlock: u32 = 2;
+    pub const DefaultSubNetworkId: SubNetworkId = SubNetworkId::Mainnet;
+    pub const SoraDecimals: u8 = 18;
 }
 
 impl xcm_app::Config for Test {
@@ -128,7 +152,13 @@ impl xcm_app::Config for Test {
     type XcmSender = ();
     type SelfLocation = SelfLocation;
     type Currency = Balances;
+    type LocalAssets = Tokens;
     type XorAssetId = XorAssetId;
+    type MaxTrappedRetriesPerBlock = MaxTrappedRetriesPerBlock;
+    type ManageOrigin = frame_system::EnsureRoot<AccountId>;
+    type PauseOrigin = frame_system::EnsureRoot<AccountId>;
+    type DefaultSubNetworkId = DefaultSubNetworkId;
+    type SoraDecimals = SoraDecimals;
 }
 
 // Build genesis storage according to the mock runtime.
@@ -140,15 +170,68 @@ pub fn test_general_key() -> [u8; 32] {
     [3; 32]
 }
 
+/// Sets metadata of the asset with Sora decimals, so that its amounts are not changed by conversion
+pub fn set_sora_decimals_metadata(asset_id: AssetId) {
+    xcm_app::Metadata::<Test>::insert(
+        asset_id,
+        xcm_app::AssetMetadata {
+            name: b"Token".to_vec().try_into().unwrap(),
+            symbol: b"TKN".to_vec().try_into().unwrap(),
+            decimals: SoraDecimals::get(),
+            existential_deposit: 0,
+        },
+    );
+}
+
+thread_local! {
+    /// Network id of messages from TestCallOrigin
+    pub static CALL_ORIGIN_NETWORK: std::cell::RefCell<SubNetworkId> =
+        std::cell::RefCell::new(SubNetworkId::Mainnet);
+    /// Networks of messages submitted to TestOutboundChannel
+    pub static SUBMITTED_NETWORKS: std::cell::RefCell<Vec<SubNetworkId>> =
+        std::cell::RefCell::new(Vec::new());
+    /// Origin of the last message submitted to TestOutboundChannel
+    pub static LAST_SUBMITTED_ORIGIN: std::cell::RefCell<Option<system::RawOrigin<AccountId>>> =
+        std::cell::RefCell::new(None);
+    /// Network TestOutboundChannel rejects messages to, as if its queue was full
+    pub static REJECTED_NETWORK: std::cell::RefCell<Option<SubNetworkId>> =
+        std::cell::RefCell::new(None);
+}
+
+pub fn set_call_origin_network(network_id: SubNetworkId) {
+    CALL_ORIGIN_NETWORK.with(|v| *v.borrow_mut() = network_id);
+}
+
+pub fn last_submitted_network() -> Option<SubNetworkId> {
+    SUBMITTED_NETWORKS.with(|v| v.borrow().last().cloned())
+}
+
+pub fn submitted_networks() -> Vec<SubNetworkId> {
+    SUBMITTED_NETWORKS.with(|v| v.borrow().clone())
+}
+
+pub fn set_rejected_network(network_id: Option<SubNetworkId>) {
+    REJECTED_NETWORK.with(|v| *v.borrow_mut() = network_id);
+}
+
+pub fn last_submitted_origin() -> Option<system::RawOrigin<AccountId>> {
+    LAST_SUBMITTED_ORIGIN.with(|v| v.borrow().clone())
+}
+
 pub struct TestOutboundChannel;
 
 impl OutboundChannel<SubNetworkId, AccountId, ()> for TestOutboundChannel {
     fn submit(
-        _network_id: SubNetworkId,
-        _who: &system::RawOrigin<AccountId>,
+        network_id: SubNetworkId,
+        who: &system::RawOrigin<AccountId>,
         _payload: &[u8],
         _additional: (),
     ) -> Result<H256, sp_runtime::DispatchError> {
+        if REJECTED_NETWORK.with(|v| *v.borrow() == Some(network_id)) {
+            return Err(sp_runtime::DispatchError::Other("Queue is full"))
+        }
+        SUBMITTED_NETWORKS.with(|v| v.borrow_mut().push(network_id));
+        LAST_SUBMITTED_ORIGIN.with(|v| *v.borrow_mut() = Some(who.clone()));
         Ok([1; 32].into())
     }
 
@@ -164,6 +247,22 @@ impl sp_runtime::traits::Convert<AccountId, MultiLocation> for TestAccountIdToMu
     }
 }
 
+thread_local! {
+    /// Currencies sent by the last XcmTransfer call and the index of the fee item
+    pub static LAST_XCM_TRANSFER: std::cell::RefCell<Option<(Vec<(AssetId, Balance)>, u32)>> =
+        std::cell::RefCell::new(None);
+    /// XcmTransfer fails after the asset is withdrawn, e.g. if the message can not be sent
+    pub static XCM_TRANSFER_FAILS: std::cell::RefCell<bool> = std::cell::RefCell::new(false);
+}
+
+pub fn last_xcm_transfer() -> Option<(Vec<(AssetId, Balance)>, u32)> {
+    LAST_XCM_TRANSFER.with(|v| v.borrow().clone())
+}
+
+pub fn set_xcm_transfer_fails(fails: bool) {
+    XCM_TRANSFER_FAILS.with(|v| *v.borrow_mut() = fails);
+}
+
 pub struct TestXcmTransfer;
 impl XcmTransfer<AccountId, Balance, AssetId> for TestXcmTransfer {
     fn transfer_multiasset(
@@ -182,12 +281,14 @@ impl XcmTransfer<AccountId, Balance, AssetId> for TestXcmTransfer {
 
     fn transfer_with_fee(
         sender: AccountId,
-        _currency_id: AssetId,
-        _amount: Balance,
-        _fee: Balance,
+        currency_id: AssetId,
+        amount: Balance,
+        fee: Balance,
         dest: MultiLocation,
         _dest_weight_limit: WeightLimit,
     ) -> Result<orml_traits::xcm_transfer::Transferred<AccountId>, sp_runtime::DispatchError> {
+        LAST_XCM_TRANSFER
+            .with(|v| *v.borrow_mut() = Some((vec![(currency_id, amount), (currency_id, fee)], 1)));
         Ok(orml_traits::xcm_transfer::Transferred {
             sender,
             dest,
@@ -208,11 +309,12 @@ impl XcmTransfer<AccountId, Balance, AssetId> for TestXcmTransfer {
 
     fn transfer_multicurrencies(
         sender: AccountId,
-        _currencies: Vec<(AssetId, Balance)>,
-        _fee_item: u32,
+        currencies: Vec<(AssetId, Balance)>,
+        fee_item: u32,
         dest: MultiLocation,
         _dest_weight_limit: WeightLimit,
     ) -> Result<orml_traits::xcm_transfer::Transferred<AccountId>, sp_runtime::DispatchError> {
+        LAST_XCM_TRANSFER.with(|v| *v.borrow_mut() = Some((currencies, fee_item)));
         Ok(orml_traits::xcm_transfer::Transferred {
             sender,
             dest,
@@ -233,11 +335,20 @@ impl XcmTransfer<AccountId, Balance, AssetId> for TestXcmTransfer {
 
     fn transfer(
         sender: AccountId,
-        _currency_id: AssetId,
-        _amount: Balance,
+        currency_id: AssetId,
+        amount: Balance,
         dest: MultiLocation,
         _dest_weight_limit: WeightLimit,
     ) -> Result<orml_traits::xcm_transfer::Transferred<AccountId>, sp_runtime::DispatchError> {
+        if XCM_TRANSFER_FAILS.with(|v| *v.borrow()) {
+            <XCMApp as orml_traits::MultiCurrency<AccountId>>::withdraw(
+                currency_id,
+                &sender,
+                amount,
+            )?;
+            return Err(sp_runtime::DispatchError::Other("Transfer failed"))
+        }
+        LAST_XCM_TRANSFER.with(|v| *v.borrow_mut() = Some((vec![(currency_id, amount)], 0)));
         Ok(orml_traits::xcm_transfer::Transferred {
             sender,
             dest,
@@ -253,7 +364,7 @@ impl<OuterOrigin: Default> frame_support::traits::EnsureOrigin<OuterOrigin> for
 
     fn try_origin(_o: OuterOrigin) -> Result<Self::Success, OuterOrigin> {
         Ok(bridge_types::types::CallOriginOutput {
-            network_id: SubNetworkId::Mainnet,
+            network_id: CALL_ORIGIN_NETWORK.with(|v| *v.borrow()),
             message_id: [1; 32].into(),
             timepoint: bridge_types::GenericTimepoint::Sora(1),
             additional: (),
@@ -28,9 +28,17 @@
 // STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use crate::{mock::*, Error};
-use bridge_types::{types::AssetKind, H256};
-use frame_support::{assert_err, assert_noop, assert_ok, traits::Currency};
+use crate::{
+    mock::*, AssetMetadata, DecimalsPair, DestinationFee, Error, TransferDirection, TransferLimit,
+    TransferVolume, TrappedMessageFilter,
+};
+use bridge_types::{types::AssetKind, SubNetworkId, H256};
+use frame_support::{
+    assert_err, assert_noop, assert_ok,
+    traits::{Currency, Get, Hooks},
+    weights::Weight,
+};
+use orml_traits::MultiCurrency;
 use sp_runtime::traits::Convert;
 use xcm::{
     opaque::latest::{
@@ -65,7 +73,7 @@ fn it_works_register_change_delete() {
         );
 
         // Change Asset's Multilocation:
-        assert_ok!(XCMApp::change_asset_mapping(asset_id, new_multilocation.clone()));
+        assert_ok!(XCMApp::do_change_asset_mapping(asset_id, new_multilocation.clone()));
         assert_eq!(
 			XCMApp::get_multilocation_from_asset_id(asset_id)
 				.expect("it_works_register_change_delete, Change Asset's Multilocation: new_multilocation is None"),
@@ -80,7 +88,10 @@ fn it_works_register_change_delete() {
         assert_eq!(XCMApp::get_asset_id_from_multilocation(multilocation.clone()), None);
 
         // Change Multilocation's Asset
-        assert_ok!(XCMApp::change_multilocation_mapping(new_multilocation.clone(), new_asset_id,));
+        assert_ok!(XCMApp::do_change_multilocation_mapping(
+            new_multilocation.clone(),
+            new_asset_id,
+        ));
         assert_eq!(
 			XCMApp::get_multilocation_from_asset_id(new_asset_id)
 				.expect("it_works_register_change_delete, Change Multilocation's Asset: new_multilocation is None"),
@@ -95,7 +106,7 @@ fn it_works_register_change_delete() {
         assert_eq!(XCMApp::get_multilocation_from_asset_id(asset_id), None);
 
         // Delete:
-        assert_ok!(XCMApp::delete_mapping(new_asset_id));
+        assert_ok!(XCMApp::do_delete_mapping(new_asset_id));
         assert_eq!(XCMApp::get_multilocation_from_asset_id(new_asset_id), None);
         assert_eq!(XCMApp::get_asset_id_from_multilocation(new_multilocation), None);
     });
@@ -152,13 +163,13 @@ fn it_fails_change_asset_non_existing_mapping() {
         let multilocation = MultiLocation::parent();
 
         assert_noop!(
-            XCMApp::change_asset_mapping(asset_id, multilocation.clone()),
+            XCMApp::do_change_asset_mapping(asset_id, multilocation.clone()),
             Error::<Test>::MappingNotExist
         );
 
         assert_ok!(XCMApp::register_mapping(new_asset_id, multilocation.clone()));
         assert_noop!(
-            XCMApp::change_asset_mapping(asset_id, multilocation.clone()),
+            XCMApp::do_change_asset_mapping(asset_id, multilocation.clone()),
             Error::<Test>::MappingNotExist
         );
         assert_eq!(
@@ -180,13 +191,13 @@ fn it_fails_change_multilocation_non_existing_mapping() {
         };
 
         assert_noop!(
-            XCMApp::change_asset_mapping(asset_id, multilocation.clone()),
+            XCMApp::do_change_asset_mapping(asset_id, multilocation.clone()),
             Error::<Test>::MappingNotExist
         );
 
         assert_ok!(XCMApp::register_mapping(asset_id, new_multilocation.clone()));
         assert_noop!(
-            XCMApp::change_multilocation_mapping(multilocation.clone(), asset_id),
+            XCMApp::do_change_multilocation_mapping(multilocation.clone(), asset_id),
             Error::<Test>::MappingNotExist
         );
         assert_eq!(
@@ -201,7 +212,7 @@ fn it_fails_change_multilocation_non_existing_mapping() {
 fn it_fails_delete_mapping_non_existing_mapping() {
     new_test_ext().execute_with(|| {
         let asset_id = [1; 32].into();
-        assert_noop!(XCMApp::delete_mapping(asset_id), Error::<Test>::MappingNotExist);
+        assert_noop!(XCMApp::do_delete_mapping(asset_id), Error::<Test>::MappingNotExist);
     });
 }
 
@@ -288,6 +299,7 @@ fn it_fails_receive_xor() {
         assert_eq!(Balances::total_balance(&bob()), 0);
         assert_err!(
             XCMApp::xcm_transfer_asset(
+                SubNetworkId::Mainnet,
                 asset_id,
                 alice(),
                 xcm::VersionedMultiLocation::V3(
@@ -307,6 +319,7 @@ fn it_fails_receive_xor() {
         assert_eq!(Balances::total_balance(&bob()), 0);
         assert_err!(
             XCMApp::xcm_transfer_asset(
+                SubNetworkId::Mainnet,
                 asset_id,
                 alice(),
                 xcm::VersionedMultiLocation::V2(
@@ -321,7 +334,7 @@ fn it_fails_receive_xor() {
                 ),
                 1000000
             ),
-            crate::Error::<Test>::WrongXCMVersion
+            crate::Error::<Test>::InvalidAssetId
         );
         assert_eq!(Balances::total_balance(&bob()), 0);
     });
@@ -334,6 +347,7 @@ fn it_works_receive_xor() {
         assert_eq!(Balances::total_balance(&bob()), 0);
         assert_eq!(Balances::total_balance(&alice()), 0);
         assert_ok!(XCMApp::xcm_transfer_asset(
+            SubNetworkId::Mainnet,
             asset_id,
             alice(),
             xcm::VersionedMultiLocation::V3(
@@ -425,3 +439,1581 @@ fn it_works_convert_foreign_asset() {
         assert_eq!(Some(asset_id), result_asset_id);
     });
 }
+
+#[test]
+fn it_works_retry_trapped_messages_on_idle() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let asset_id = [1; 32].into();
+
+        // nothing is charged if there is nothing to retry
+        assert_eq!(XCMApp::on_idle(1, Weight::MAX), Weight::zero());
+
+        for amount in 1..=5 {
+            XCMApp::trap_asset(SubNetworkId::Mainnet, None, asset_id, alice(), amount, false);
+        }
+        assert_eq!(crate::BridgeAssetTrap::<Test>::iter().count(), 5);
+
+        // not enough weight to retry anything
+        assert_eq!(XCMApp::on_idle(1, Weight::zero()), Weight::zero());
+        assert_eq!(crate::BridgeAssetTrap::<Test>::iter().count(), 5);
+
+        // only retries covered by the weight are taken,
+        // reads of the keys and the update of the retry cursor are charged
+        let one_retry = <() as crate::weights::WeightInfo>::try_claim_bridge_asset()
+            .saturating_add(<Test as frame_system::Config>::DbWeight::get().reads_writes(2, 1));
+        assert_eq!(XCMApp::on_idle(1, one_retry), one_retry);
+        assert_eq!(crate::BridgeAssetTrap::<Test>::iter().count(), 4);
+
+        // exactly MaxTrappedRetriesPerBlock messages are retried per block
+        crate::BridgeAssetTrapRetryCursor::<Test>::kill();
+        XCMApp::on_idle(2, Weight::MAX);
+        assert_eq!(crate::BridgeAssetTrap::<Test>::iter().count(), 2);
+
+        for n in 3..=5 {
+            XCMApp::on_idle(n, Weight::MAX);
+        }
+        assert_eq!(crate::BridgeAssetTrap::<Test>::iter().count(), 0);
+        for nonce in 1..=5 {
+            assert!(System::events()
+                .iter()
+                .any(|r| r.event ==
+                    RuntimeEvent::XCMApp(crate::Event::BridgeAssetTrapRetried(nonce))));
+        }
+    });
+}
+
+#[test]
+fn it_works_resend_trapped_done_result() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let message_id = H256::repeat_byte(1);
+        assert_noop!(
+            XCMApp::resend_trapped_done_result(RuntimeOrigin::signed(alice()), message_id),
+            Error::<Test>::TrappedDoneResultNotFound
+        );
+        crate::TrappedDoneResult::<Test>::insert(
+            message_id,
+            crate::TrappedDoneResultInfo { network_id: SubNetworkId::Mainnet, sender: Some(bob()) },
+        );
+        assert_noop!(
+            XCMApp::resend_trapped_done_result(RuntimeOrigin::none(), message_id),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(XCMApp::resend_trapped_done_result(RuntimeOrigin::signed(alice()), message_id));
+        assert!(!crate::TrappedDoneResult::<Test>::contains_key(message_id));
+        // the report is sent on behalf of the original sender, not the caller
+        assert_eq!(last_submitted_origin(), Some(frame_system::RawOrigin::Signed(bob())));
+        System::assert_last_event(RuntimeEvent::XCMApp(crate::Event::TrappedDoneResultSent(
+            message_id,
+        )));
+
+        // senders of legacy results are unknown
+        crate::TrappedDoneResult::<Test>::insert(
+            message_id,
+            crate::TrappedDoneResultInfo { network_id: SubNetworkId::Mainnet, sender: None },
+        );
+        assert_ok!(XCMApp::resend_trapped_done_result(RuntimeOrigin::signed(alice()), message_id));
+        assert_eq!(last_submitted_origin(), Some(frame_system::RawOrigin::Root));
+    });
+}
+
+#[test]
+fn it_works_resend_trapped_done_results_on_idle() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let info = crate::TrappedDoneResultInfo {
+            network_id: SubNetworkId::Mainnet,
+            sender: Some(alice()),
+        };
+        crate::TrappedDoneResult::<Test>::insert(H256::repeat_byte(1), info.clone());
+        crate::TrappedDoneResult::<Test>::insert(H256::repeat_byte(2), info);
+        XCMApp::trap_asset(SubNetworkId::Mainnet, None, [1; 32].into(), alice(), 100, false);
+
+        // done results and trapped messages share MaxTrappedRetriesPerBlock limit
+        XCMApp::on_idle(1, Weight::MAX);
+        assert_eq!(crate::TrappedDoneResult::<Test>::iter().count(), 0);
+        assert_eq!(crate::BridgeAssetTrap::<Test>::iter().count(), 1);
+
+        XCMApp::on_idle(2, Weight::MAX);
+        assert_eq!(crate::BridgeAssetTrap::<Test>::iter().count(), 0);
+    });
+}
+
+#[test]
+fn it_works_retry_skips_failing_trapped_done_result() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let info = |network_id| crate::TrappedDoneResultInfo { network_id, sender: Some(alice()) };
+        crate::TrappedDoneResult::<Test>::insert(H256::repeat_byte(1), info(SubNetworkId::Kusama));
+        for byte in 2..=4 {
+            crate::TrappedDoneResult::<Test>::insert(
+                H256::repeat_byte(byte),
+                info(SubNetworkId::Mainnet),
+            );
+        }
+        set_rejected_network(Some(SubNetworkId::Kusama));
+
+        // the result which keeps failing does not hold back the others
+        for n in 1..=4 {
+            XCMApp::on_idle(n, Weight::MAX);
+        }
+        assert_eq!(
+            crate::TrappedDoneResult::<Test>::iter_keys().collect::<Vec<_>>(),
+            [H256::repeat_byte(1)]
+        );
+
+        set_rejected_network(None);
+        XCMApp::on_idle(5, Weight::MAX);
+        assert_eq!(crate::TrappedDoneResult::<Test>::iter().count(), 0);
+    });
+}
+
+#[test]
+fn it_works_trapped_messages_filter_and_pagination() {
+    new_test_ext().execute_with(|| {
+        let asset_id = [1; 32].into();
+        let other_asset_id = [2; 32].into();
+        XCMApp::trap_asset(SubNetworkId::Mainnet, None, asset_id, alice(), 100, false);
+        XCMApp::trap_asset(SubNetworkId::Mainnet, None, other_asset_id, bob(), 200, false);
+        XCMApp::trap_asset(SubNetworkId::Mainnet, None, asset_id, bob(), 300, false);
+
+        let nonces = |messages: Vec<(u128, crate::TrappedMessage<_>)>| {
+            messages.into_iter().map(|(nonce, _)| nonce).collect::<Vec<_>>()
+        };
+        assert_eq!(nonces(XCMApp::trapped_messages(TrappedMessageFilter::All, 0, 10)), [1, 2, 3]);
+        assert_eq!(nonces(XCMApp::trapped_messages(TrappedMessageFilter::All, 1, 1)), [2]);
+        assert_eq!(
+            nonces(XCMApp::trapped_messages(TrappedMessageFilter::Recipient(bob()), 0, 10)),
+            [2, 3]
+        );
+        assert_eq!(
+            nonces(XCMApp::trapped_messages(TrappedMessageFilter::AssetId(asset_id), 0, 10)),
+            [1, 3]
+        );
+        assert_eq!(XCMApp::bridge_asset_trap_nonce(), 3);
+    });
+}
+
+#[test]
+fn it_works_set_asset_transfer_limit() {
+    new_test_ext().execute_with(|| {
+        let asset_id = [1; 32].into();
+        let limit =
+            TransferLimit { max_transfer_amount: Some(100), max_period_volume: None, period: 0 };
+        assert_ok!(XCMApp::set_asset_transfer_limit(RuntimeOrigin::root(), asset_id, Some(limit)));
+        assert_eq!(XCMApp::asset_transfer_limit(asset_id), Some(limit));
+
+        let invalid_limit =
+            TransferLimit { max_transfer_amount: None, max_period_volume: Some(100), period: 0 };
+        assert_noop!(
+            XCMApp::set_asset_transfer_limit(RuntimeOrigin::root(), asset_id, Some(invalid_limit)),
+            Error::<Test>::InvalidTransferLimit
+        );
+
+        assert_ok!(XCMApp::set_asset_transfer_limit(RuntimeOrigin::root(), asset_id, None));
+        assert_eq!(XCMApp::asset_transfer_limit(asset_id), None);
+    });
+}
+
+#[test]
+fn it_works_transfer_limit_refunds_throttled_transfers() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let asset_id = [1; 32].into();
+        set_sora_decimals_metadata(asset_id);
+        let recipient = xcm::VersionedMultiLocation::V3(MultiLocation::new(
+            1,
+            X2(Parachain(666), GeneralKey { length: 32, data: test_general_key() }),
+        ));
+        let limit = TransferLimit {
+            max_transfer_amount: Some(100),
+            max_period_volume: Some(150),
+            period: 10,
+        };
+        assert_ok!(XCMApp::set_asset_transfer_limit(RuntimeOrigin::root(), asset_id, Some(limit)));
+        let throttled = |amount| {
+            System::events().iter().any(|r| {
+                r.event == RuntimeEvent::XCMApp(crate::Event::TransferThrottled(asset_id, amount))
+            })
+        };
+
+        // exceeds the per-transfer maximum
+        assert_ok!(XCMApp::transfer(
+            RuntimeOrigin::root(),
+            asset_id,
+            alice(),
+            recipient.clone(),
+            200
+        ));
+        assert!(throttled(200));
+        System::assert_last_event(RuntimeEvent::XCMApp(crate::Event::AssetRefundSent(
+            [1; 32].into(),
+            alice(),
+            asset_id,
+            200,
+        )));
+
+        assert_ok!(XCMApp::transfer(
+            RuntimeOrigin::root(),
+            asset_id,
+            alice(),
+            recipient.clone(),
+            100
+        ));
+        assert_eq!(XCMApp::asset_transfer_volume(asset_id).volume, 100);
+
+        // exceeds the period volume
+        assert_ok!(XCMApp::transfer(
+            RuntimeOrigin::root(),
+            asset_id,
+            alice(),
+            recipient.clone(),
+            100
+        ));
+        assert!(throttled(100));
+        assert_eq!(XCMApp::asset_transfer_volume(asset_id).volume, 100);
+
+        // the previous transfers are out of the rolling window
+        System::set_block_number(21);
+        assert_ok!(XCMApp::transfer(RuntimeOrigin::root(), asset_id, alice(), recipient, 100));
+        assert_eq!(
+            XCMApp::asset_transfer_volume(asset_id),
+            TransferVolume { period_start: 20, volume: 100, previous_volume: 0 }
+        );
+    });
+}
+
+#[test]
+fn it_works_transfer_limit_rolling_window() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let asset_id = [1; 32].into();
+        set_sora_decimals_metadata(asset_id);
+        let recipient = xcm::VersionedMultiLocation::V3(MultiLocation::new(
+            1,
+            X2(Parachain(666), GeneralKey { length: 32, data: test_general_key() }),
+        ));
+        let limit =
+            TransferLimit { max_transfer_amount: None, max_period_volume: Some(150), period: 10 };
+        assert_ok!(XCMApp::set_asset_transfer_limit(RuntimeOrigin::root(), asset_id, Some(limit)));
+        let transfer = |amount| {
+            assert_ok!(XCMApp::transfer(
+                RuntimeOrigin::root(),
+                asset_id,
+                alice(),
+                recipient.clone(),
+                amount
+            ));
+            XCMApp::asset_transfer_volume(asset_id)
+        };
+
+        System::set_block_number(9);
+        assert_eq!(
+            transfer(150),
+            TransferVolume { period_start: 0, volume: 150, previous_volume: 0 }
+        );
+
+        // crossing the period boundary does not reset the volume,
+        // 90% of the previous period is still in the rolling window
+        System::set_block_number(11);
+        assert_eq!(
+            transfer(100),
+            TransferVolume { period_start: 0, volume: 150, previous_volume: 0 }
+        );
+        System::assert_has_event(RuntimeEvent::XCMApp(crate::Event::TransferThrottled(
+            asset_id, 100,
+        )));
+        assert_eq!(
+            transfer(15),
+            TransferVolume { period_start: 10, volume: 15, previous_volume: 150 }
+        );
+
+        // the previous period leaves the rolling window gradually
+        System::set_block_number(15);
+        assert_eq!(
+            transfer(60),
+            TransferVolume { period_start: 10, volume: 75, previous_volume: 150 }
+        );
+
+        // the whole previous period is in the rolling window when the next one starts
+        System::set_block_number(20);
+        transfer(80);
+        System::assert_has_event(RuntimeEvent::XCMApp(crate::Event::TransferThrottled(
+            asset_id, 80,
+        )));
+        assert_eq!(
+            transfer(75),
+            TransferVolume { period_start: 20, volume: 75, previous_volume: 75 }
+        );
+    });
+}
+
+#[test]
+fn it_works_pause_outbound_transfers() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let asset_id = [1; 32].into();
+        set_sora_decimals_metadata(asset_id);
+        let recipient = xcm::VersionedMultiLocation::V3(MultiLocation::new(
+            1,
+            X2(Parachain(666), GeneralKey { length: 32, data: test_general_key() }),
+        ));
+        assert_noop!(
+            XCMApp::set_direction_paused(
+                RuntimeOrigin::signed(alice()),
+                TransferDirection::Outbound,
+                true
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(XCMApp::set_direction_paused(
+            RuntimeOrigin::root(),
+            TransferDirection::Outbound,
+            true
+        ));
+        assert_ok!(XCMApp::transfer(
+            RuntimeOrigin::root(),
+            asset_id,
+            alice(),
+            recipient.clone(),
+            100
+        ));
+        System::assert_last_event(RuntimeEvent::XCMApp(crate::Event::AssetRefundSent(
+            [1; 32].into(),
+            alice(),
+            asset_id,
+            100,
+        )));
+
+        assert_ok!(XCMApp::set_direction_paused(
+            RuntimeOrigin::root(),
+            TransferDirection::Outbound,
+            false
+        ));
+        assert_ok!(XCMApp::set_asset_paused(RuntimeOrigin::root(), asset_id, true));
+        assert_ok!(XCMApp::transfer(
+            RuntimeOrigin::root(),
+            asset_id,
+            alice(),
+            recipient.clone(),
+            200
+        ));
+        System::assert_last_event(RuntimeEvent::XCMApp(crate::Event::AssetRefundSent(
+            [1; 32].into(),
+            alice(),
+            asset_id,
+            200,
+        )));
+
+        assert_ok!(XCMApp::set_asset_paused(RuntimeOrigin::root(), asset_id, false));
+        assert_ok!(XCMApp::transfer(RuntimeOrigin::root(), asset_id, alice(), recipient, 300));
+        assert!(!System::events().iter().any(|r| {
+            r.event ==
+                RuntimeEvent::XCMApp(crate::Event::AssetRefundSent(
+                    [1; 32].into(),
+                    alice(),
+                    asset_id,
+                    300,
+                ))
+        }));
+    });
+}
+
+#[test]
+fn it_fails_inbound_transfers_when_paused() {
+    new_test_ext().execute_with(|| {
+        let asset_id = [1; 32].into();
+        let _ = Balances::deposit_creating(&alice(), 1000);
+        assert_ok!(XCMApp::set_direction_paused(
+            RuntimeOrigin::root(),
+            TransferDirection::Inbound,
+            true
+        ));
+        assert_noop!(
+            <XCMApp as MultiCurrency<_>>::deposit(asset_id, &alice(), 100),
+            Error::<Test>::DirectionPaused
+        );
+        assert_noop!(
+            XCMApp::send_xor_to_mainnet(RuntimeOrigin::signed(alice()), bob(), 100),
+            Error::<Test>::DirectionPaused
+        );
+
+        assert_ok!(XCMApp::set_direction_paused(
+            RuntimeOrigin::root(),
+            TransferDirection::Inbound,
+            false
+        ));
+        assert_ok!(XCMApp::set_asset_paused(RuntimeOrigin::root(), asset_id, true));
+        assert_noop!(
+            <XCMApp as MultiCurrency<_>>::deposit(asset_id, &alice(), 100),
+            Error::<Test>::AssetPaused
+        );
+        set_sora_decimals_metadata([2; 32].into());
+        assert_ok!(<XCMApp as MultiCurrency<_>>::deposit([2; 32].into(), &alice(), 100));
+    });
+}
+
+#[test]
+fn it_fails_claim_trapped_messages_when_paused() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let asset_id = [1; 32].into();
+        let other_asset_id = [2; 32].into();
+        XCMApp::trap_asset(SubNetworkId::Mainnet, None, asset_id, alice(), 100, false);
+        XCMApp::trap_asset(SubNetworkId::Mainnet, None, other_asset_id, alice(), 100, false);
+        XCMApp::trap_asset(
+            SubNetworkId::Mainnet,
+            Some(H256::repeat_byte(1)),
+            asset_id,
+            alice(),
+            100,
+            true,
+        );
+
+        assert_ok!(XCMApp::set_direction_paused(
+            RuntimeOrigin::root(),
+            TransferDirection::Inbound,
+            true
+        ));
+        assert_noop!(
+            XCMApp::try_claim_bridge_asset(RuntimeOrigin::root(), 1),
+            Error::<Test>::DirectionPaused
+        );
+        assert_ok!(XCMApp::set_direction_paused(
+            RuntimeOrigin::root(),
+            TransferDirection::Inbound,
+            false
+        ));
+
+        // paused messages are skipped by the retries and stay trapped,
+        // refunds are not paused
+        assert_ok!(XCMApp::set_asset_paused(RuntimeOrigin::root(), asset_id, true));
+        XCMApp::on_idle(1, Weight::MAX);
+        XCMApp::on_idle(2, Weight::MAX);
+        assert_eq!(crate::BridgeAssetTrap::<Test>::iter_keys().collect::<Vec<_>>(), vec![1]);
+    });
+}
+
+#[test]
+fn it_rejects_xcm_with_paused_assets() {
+    use xcm_executor::traits::ShouldExecute;
+    type Barrier = crate::DenyPausedAssets<
+        xcm_builder::AllowTopLevelPaidExecutionFrom<frame_support::traits::Everything>,
+        Test,
+    >;
+
+    new_test_ext().execute_with(|| {
+        let asset_id = [1; 32].into();
+        assert_ok!(XCMApp::register_mapping(asset_id, MultiLocation::parent()));
+        let should_execute = || {
+            let fees: xcm::v3::MultiAsset = (MultiLocation::parent(), 1_000).into();
+            let mut instructions = [
+                xcm::v3::Instruction::<()>::ReserveAssetDeposited(fees.clone().into()),
+                xcm::v3::Instruction::<()>::BuyExecution {
+                    fees,
+                    weight_limit: xcm::v3::WeightLimit::Unlimited,
+                },
+            ];
+            Barrier::should_execute(
+                &MultiLocation::parent(),
+                &mut instructions,
+                Weight::MAX,
+                &mut Weight::zero(),
+            )
+        };
+        assert_eq!(should_execute(), Ok(()));
+
+        assert_ok!(XCMApp::set_asset_paused(RuntimeOrigin::root(), asset_id, true));
+        assert_eq!(should_execute(), Err(()));
+        assert_ok!(XCMApp::set_asset_paused(RuntimeOrigin::root(), asset_id, false));
+
+        assert_ok!(XCMApp::set_direction_paused(
+            RuntimeOrigin::root(),
+            TransferDirection::Inbound,
+            true
+        ));
+        assert_eq!(should_execute(), Err(()));
+        // outbound pause doesn't affect incoming messages
+        assert_ok!(XCMApp::set_direction_paused(
+            RuntimeOrigin::root(),
+            TransferDirection::Inbound,
+            false
+        ));
+        assert_ok!(XCMApp::set_direction_paused(
+            RuntimeOrigin::root(),
+            TransferDirection::Outbound,
+            true
+        ));
+        assert_eq!(should_execute(), Ok(()));
+    });
+}
+
+#[test]
+fn it_works_manage_mapping_extrinsics() {
+    new_test_ext().execute_with(|| {
+        let asset_id = [1; 32].into();
+        let new_asset_id = [2; 32].into();
+        let multilocation = MultiLocation::parent();
+        let new_multilocation = MultiLocation {
+            parents: 1,
+            interior: X2(Parachain(666), GeneralKey { length: 6, data: test_general_key() }),
+        };
+        assert_ok!(XCMApp::register_mapping(asset_id, multilocation));
+        crate::AssetMinimumAmount::<Test>::insert(multilocation, 1000);
+
+        assert_noop!(
+            XCMApp::change_asset_mapping(
+                RuntimeOrigin::signed(alice()),
+                asset_id,
+                new_multilocation.into()
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(XCMApp::change_asset_mapping(
+            RuntimeOrigin::root(),
+            asset_id,
+            new_multilocation.into()
+        ));
+        assert_eq!(XCMApp::asset_minimum_amount(multilocation), None);
+        assert_eq!(XCMApp::asset_minimum_amount(new_multilocation), Some(1000));
+
+        set_sora_decimals_metadata(asset_id);
+        // mapping with amounts in flight can not be changed
+        crate::AssetDust::<Test>::insert(asset_id, 1);
+        assert_noop!(
+            XCMApp::change_multilocation_mapping(
+                RuntimeOrigin::root(),
+                new_multilocation.into(),
+                new_asset_id
+            ),
+            Error::<Test>::AssetInFlight
+        );
+        crate::AssetDust::<Test>::remove(asset_id);
+
+        // metadata is moved to the new asset id
+        let metadata = XCMApp::asset_metadata(asset_id);
+        assert_ok!(XCMApp::change_multilocation_mapping(
+            RuntimeOrigin::root(),
+            new_multilocation.into(),
+            new_asset_id
+        ));
+        assert_eq!(XCMApp::get_multilocation_from_asset_id(new_asset_id), Some(new_multilocation));
+        assert_eq!(XCMApp::asset_minimum_amount(new_multilocation), Some(1000));
+        assert_eq!(XCMApp::asset_metadata(asset_id), None);
+        assert_eq!(XCMApp::asset_metadata(new_asset_id), metadata);
+
+        assert_noop!(
+            XCMApp::delete_mapping(RuntimeOrigin::signed(alice()), new_asset_id),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        // mapping with amounts in flight can not be deleted
+        crate::Accounting::<Test>::mutate(new_asset_id, |accounting| accounting.deposited = 10);
+        assert_noop!(
+            XCMApp::delete_mapping(RuntimeOrigin::root(), new_asset_id),
+            Error::<Test>::AssetInFlight
+        );
+        crate::Accounting::<Test>::mutate(new_asset_id, |accounting| accounting.forwarded = 10);
+        assert_ok!(XCMApp::delete_mapping(RuntimeOrigin::root(), new_asset_id));
+        assert_eq!(XCMApp::get_multilocation_from_asset_id(new_asset_id), None);
+        assert_eq!(XCMApp::get_asset_id_from_multilocation(new_multilocation), None);
+        assert_eq!(XCMApp::asset_minimum_amount(new_multilocation), None);
+    });
+}
+
+#[test]
+fn it_works_transfer_to_v2_recipient() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let asset_id = [1; 32].into();
+        set_sora_decimals_metadata(asset_id);
+        let recipient = xcm::v2::MultiLocation::new(
+            1,
+            xcm::v2::Junctions::X2(
+                xcm::v2::Junction::Parachain(666),
+                xcm::v2::Junction::AccountId32 {
+                    network: xcm::v2::NetworkId::Any,
+                    id: bob().into(),
+                },
+            ),
+        );
+        assert_ok!(XCMApp::xcm_transfer_asset(
+            SubNetworkId::Mainnet,
+            asset_id,
+            alice(),
+            xcm::VersionedMultiLocation::V2(recipient.clone()),
+            1000000
+        ));
+        System::assert_last_event(RuntimeEvent::XCMApp(crate::Event::AssetTransferred(
+            alice(),
+            recipient.try_into().unwrap(),
+            asset_id,
+            1000000,
+        )));
+    });
+}
+
+#[test]
+fn it_works_register_abstract_asset() {
+    new_test_ext().execute_with(|| {
+        let asset_id = [1; 32].into();
+        let abstract_id = [7; 32];
+        assert_ok!(XCMApp::register_asset(
+            RuntimeOrigin::root(),
+            asset_id,
+            xcm::v3::AssetId::Abstract(abstract_id),
+            AssetKind::Sidechain,
+            100000,
+        ));
+        assert_eq!(XCMApp::get_abstract_from_asset_id(asset_id), Some(abstract_id));
+        assert_eq!(XCMApp::get_asset_id_from_abstract(abstract_id), Some(asset_id));
+        assert_eq!(XCMApp::abstract_asset_minimum_amount(abstract_id), Some(100000));
+        assert_noop!(
+            XCMApp::register_asset(
+                RuntimeOrigin::root(),
+                asset_id,
+                MultiLocation::parent().into(),
+                AssetKind::Sidechain,
+                100000,
+            ),
+            Error::<Test>::MappingAlreadyExists
+        );
+
+        let multiasset = |amount| xcm::v3::MultiAsset {
+            id: xcm::v3::AssetId::Abstract(abstract_id),
+            fun: xcm::v3::Fungibility::Fungible(amount),
+        };
+        // amounts below the minimum amount are not converted
+        assert_eq!(XCMApp::convert(multiasset(100)), None);
+        assert_eq!(XCMApp::convert(multiasset(100000)), Some(asset_id));
+
+        assert_ok!(XCMApp::set_asset_minimum_amount(RuntimeOrigin::root(), asset_id, 500));
+        assert_eq!(XCMApp::abstract_asset_minimum_amount(abstract_id), Some(500));
+        assert_eq!(XCMApp::convert(multiasset(499)), None);
+        assert_eq!(XCMApp::convert(multiasset(500)), Some(asset_id));
+
+        // the asset id mapped to the abstract id can not be mapped to a multilocation
+        assert_ok!(XCMApp::register_mapping([2; 32].into(), MultiLocation::parent()));
+        assert_noop!(
+            XCMApp::change_multilocation_mapping(
+                RuntimeOrigin::root(),
+                MultiLocation::parent().into(),
+                asset_id
+            ),
+            Error::<Test>::MappingAlreadyExists
+        );
+        assert_eq!(XCMApp::get_multilocation_from_asset_id(asset_id), None);
+        assert_eq!(XCMApp::get_abstract_from_asset_id(asset_id), Some(abstract_id));
+
+        assert_err!(
+            XCMApp::xcm_transfer_asset(
+                SubNetworkId::Mainnet,
+                asset_id,
+                alice(),
+                MultiLocation::new(1, X1(Parachain(666))).into(),
+                1000
+            ),
+            Error::<Test>::UnsupportedAssetIdKind
+        );
+
+        assert_ok!(XCMApp::do_delete_mapping(asset_id));
+        assert_eq!(XCMApp::get_abstract_from_asset_id(asset_id), None);
+        assert_eq!(XCMApp::get_asset_id_from_abstract(abstract_id), None);
+        assert_eq!(XCMApp::abstract_asset_minimum_amount(abstract_id), None);
+    });
+}
+
+#[test]
+fn it_works_asset_accounting() {
+    new_test_ext().execute_with(|| {
+        let asset_id = [1; 32].into();
+        set_sora_decimals_metadata(asset_id);
+        assert_ok!(<XCMApp as MultiCurrency<_>>::deposit(asset_id, &alice(), 100));
+        XCMApp::trap_asset(SubNetworkId::Mainnet, None, asset_id, alice(), 50, false);
+        crate::Accounting::<Test>::mutate(asset_id, |accounting| accounting.deposited += 50);
+        assert_eq!(
+            XCMApp::asset_accounting(asset_id),
+            crate::AssetAccounting { deposited: 150, returned: 0, forwarded: 100, withdrawn: 0 }
+        );
+        assert_eq!(<XCMApp as MultiCurrency<_>>::total_issuance(asset_id), 50);
+        assert_ok!(XCMApp::do_try_state());
+
+        assert_ok!(XCMApp::do_claim_bridge_asset(1));
+        assert_eq!(<XCMApp as MultiCurrency<_>>::total_issuance(asset_id), 0);
+        assert_ok!(XCMApp::do_try_state());
+
+        assert_ok!(<XCMApp as MultiCurrency<_>>::withdraw(asset_id, &alice(), 30));
+        assert_eq!(
+            XCMApp::asset_accounting(asset_id),
+            crate::AssetAccounting { deposited: 150, returned: 0, forwarded: 150, withdrawn: 30 }
+        );
+
+        // trapped amount without accounting breaks the invariant
+        XCMApp::trap_asset(SubNetworkId::Mainnet, None, asset_id, alice(), 10, false);
+        assert!(XCMApp::do_try_state().is_err());
+    });
+}
+
+#[test]
+fn it_works_asset_accounting_reconciliation() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let asset_id = XorAssetId::get();
+        set_sora_decimals_metadata(asset_id);
+        let _ = Balances::deposit_creating(&alice(), 10000000);
+        assert_ok!(<XCMApp as MultiCurrency<_>>::deposit(asset_id, &alice(), 100));
+        // local sends are returned to Sora, not deposited through XCM
+        assert_ok!(XCMApp::send_xor_to_mainnet(RuntimeOrigin::signed(alice()), bob(), 1000));
+        assert_ok!(<XCMApp as MultiCurrency<_>>::withdraw(asset_id, &alice(), 30));
+
+        let accounting = XCMApp::asset_accounting(asset_id);
+        assert_eq!(
+            accounting,
+            crate::AssetAccounting {
+                deposited: 100,
+                returned: 1000,
+                forwarded: 1100,
+                withdrawn: 30
+            }
+        );
+        assert_eq!(accounting.in_flight(), 0);
+        // local sends are not counted in the amount sent through XCM
+        assert_eq!(accounting.net_withdrawn(), -70);
+        assert_ok!(XCMApp::do_try_state());
+
+        // forwarded amount matches the transfers submitted to Sora
+        let submitted = System::events()
+            .into_iter()
+            .filter_map(|r| match r.event {
+                RuntimeEvent::XCMApp(crate::Event::AssetAddedToChannel(
+                    bridge_types::substrate::ParachainAppCall::Transfer { amount, .. },
+                )) => Some(amount),
+                _ => None,
+            })
+            .sum::<u128>();
+        assert_eq!(submitted, accounting.forwarded);
+    });
+}
+
+#[test]
+fn it_works_storage_migrations() {
+    use crate::migrations::{v0, v1, v2, v3};
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<XCMApp>();
+        let asset_id = [1; 32].into();
+        let message = |message_id, amount, is_refund| v0::TrappedMessage {
+            asset_id,
+            recipient: alice(),
+            message_id,
+            amount,
+            is_refund,
+        };
+        v0::BridgeAssetTrap::<Test>::insert(1, message(None, 100, false));
+        v0::BridgeAssetTrap::<Test>::insert(2, message(None, 50, false));
+        v0::BridgeAssetTrap::<Test>::insert(3, message(Some(H256::repeat_byte(1)), 70, true));
+        frame_support::storage::unhashed::put(
+            &crate::TrappedDoneResult::<Test>::hashed_key_for(H256::repeat_byte(2)),
+            &(),
+        );
+
+        v1::InitializeAccounting::<Test>::on_runtime_upgrade();
+        assert_eq!(XCMApp::on_chain_storage_version(), 1);
+        assert_eq!(XCMApp::asset_accounting(asset_id).deposited, 150);
+
+        // migration is not applied twice
+        v1::InitializeAccounting::<Test>::on_runtime_upgrade();
+        assert_eq!(XCMApp::asset_accounting(asset_id).deposited, 150);
+
+        v2::AddNetworkId::<Test>::on_runtime_upgrade();
+        assert_eq!(XCMApp::on_chain_storage_version(), 2);
+        assert_eq!(
+            XCMApp::bridge_asset_trap(3),
+            Some(crate::TrappedMessage {
+                network_id: SubNetworkId::Mainnet,
+                asset_id,
+                recipient: alice(),
+                message_id: Some(H256::repeat_byte(1)),
+                amount: 70,
+                is_refund: true,
+            })
+        );
+        assert_eq!(
+            XCMApp::trapped_done_result(H256::repeat_byte(2)),
+            Some(crate::TrappedDoneResultInfo { network_id: SubNetworkId::Mainnet, sender: None })
+        );
+        assert_ok!(XCMApp::do_try_state());
+
+        let sibling = MultiLocation::new(1, X1(Parachain(666)));
+        let other_sibling = MultiLocation::new(1, X1(Parachain(777)));
+        assert_ok!(XCMApp::register_mapping(
+            [2; 32].into(),
+            MultiLocation::new(
+                1,
+                X2(Parachain(666), GeneralKey { length: 32, data: test_general_key() })
+            )
+        ));
+        assert_ok!(XCMApp::register_mapping([3; 32].into(), sibling));
+        assert_ok!(XCMApp::register_mapping([4; 32].into(), MultiLocation::parent()));
+        assert_eq!(XCMApp::trusted_xcm_origin(MultiLocation::parent()), None);
+        frame_support::parameter_types! {
+            pub TrustedOrigins: Vec<MultiLocation> = vec![MultiLocation::new(1, X1(Parachain(777)))];
+        }
+        v3::TrustRelayChain::<Test, TrustedOrigins>::on_runtime_upgrade();
+        assert_eq!(XCMApp::on_chain_storage_version(), 3);
+        assert_eq!(XCMApp::trusted_xcm_origin(MultiLocation::parent()), Some(()));
+        // only the listed origins are trusted, not the ones the mapped assets originate from
+        assert_eq!(XCMApp::trusted_xcm_origin(other_sibling), Some(()));
+        assert_eq!(XCMApp::trusted_xcm_origin(sibling), None);
+        assert_eq!(crate::TrustedXcmOrigins::<Test>::iter_keys().count(), 2);
+    });
+}
+
+#[test]
+fn it_works_set_destination_weight_limit() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let weight = Weight::from_parts(1_000_000_000, 65536);
+        let sibling = MultiLocation::new(
+            1,
+            X2(Parachain(666), GeneralKey { length: 32, data: test_general_key() }),
+        );
+        let other_sibling = MultiLocation::new(1, X1(Parachain(777)));
+        assert_eq!(XCMApp::weight_limit_for(&sibling), xcm::v3::WeightLimit::Unlimited);
+
+        assert_noop!(
+            XCMApp::set_destination_weight_limit(RuntimeOrigin::signed(alice()), 666, Some(weight)),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(XCMApp::set_destination_weight_limit(RuntimeOrigin::root(), 666, Some(weight)));
+        assert_eq!(XCMApp::destination_weight_limit(666), Some(weight));
+        System::assert_last_event(RuntimeEvent::XCMApp(crate::Event::DestinationWeightLimitSet(
+            666,
+            Some(weight),
+        )));
+        assert_eq!(XCMApp::weight_limit_for(&sibling), xcm::v3::WeightLimit::Limited(weight));
+        assert_eq!(XCMApp::weight_limit_for(&other_sibling), xcm::v3::WeightLimit::Unlimited);
+        assert_eq!(
+            XCMApp::weight_limit_for(&MultiLocation::parent()),
+            xcm::v3::WeightLimit::Unlimited
+        );
+
+        assert_ok!(XCMApp::set_destination_weight_limit(RuntimeOrigin::root(), 666, None));
+        assert_eq!(XCMApp::destination_weight_limit(666), None);
+        assert_eq!(XCMApp::weight_limit_for(&sibling), xcm::v3::WeightLimit::Unlimited);
+    });
+}
+
+#[test]
+fn it_works_transfer_with_destination_fee() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let asset_id = [1; 32].into();
+        set_sora_decimals_metadata(asset_id);
+        let fee_asset_id = [3; 32].into();
+        let recipient = xcm::VersionedMultiLocation::V3(MultiLocation::new(
+            1,
+            X2(Parachain(666), GeneralKey { length: 32, data: test_general_key() }),
+        ));
+
+        // fee is paid from the transferred amount if not set
+        assert_ok!(XCMApp::transfer(
+            RuntimeOrigin::root(),
+            asset_id,
+            alice(),
+            recipient.clone(),
+            100
+        ));
+        assert_eq!(last_xcm_transfer(), Some((vec![(asset_id, 100)], 0)));
+
+        let fee = DestinationFee { asset_id, amount: 10 };
+        assert_noop!(
+            XCMApp::set_destination_fee(RuntimeOrigin::signed(alice()), 666, Some(fee)),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(XCMApp::set_destination_fee(RuntimeOrigin::root(), 666, Some(fee)));
+        System::assert_last_event(RuntimeEvent::XCMApp(crate::Event::DestinationFeeSet(
+            666,
+            Some(fee),
+        )));
+
+        // fee in the transferred asset is deducted from the amount
+        assert_ok!(XCMApp::transfer(
+            RuntimeOrigin::root(),
+            asset_id,
+            alice(),
+            recipient.clone(),
+            100
+        ));
+        assert_eq!(last_xcm_transfer(), Some((vec![(asset_id, 90), (asset_id, 10)], 1)));
+
+        // transfer is refunded if the fee is not less than the amount
+        assert_ok!(XCMApp::transfer(
+            RuntimeOrigin::root(),
+            asset_id,
+            alice(),
+            recipient.clone(),
+            10
+        ));
+        System::assert_last_event(RuntimeEvent::XCMApp(crate::Event::AssetRefundSent(
+            [1; 32].into(),
+            alice(),
+            asset_id,
+            10,
+        )));
+
+        // fee in another asset is not sent, the fee is paid from the transferred amount
+        let fee = DestinationFee { asset_id: fee_asset_id, amount: 10 };
+        assert_ok!(XCMApp::set_destination_fee(RuntimeOrigin::root(), 666, Some(fee)));
+        assert_ok!(XCMApp::transfer(
+            RuntimeOrigin::root(),
+            asset_id,
+            alice(),
+            recipient.clone(),
+            100
+        ));
+        assert_eq!(last_xcm_transfer(), Some((vec![(asset_id, 100)], 0)));
+        assert_eq!(XCMApp::asset_accounting(fee_asset_id), Default::default());
+
+        assert_ok!(XCMApp::set_destination_fee(RuntimeOrigin::root(), 666, None));
+        assert_eq!(XCMApp::destination_fee(666), None);
+        assert_ok!(XCMApp::transfer(RuntimeOrigin::root(), asset_id, alice(), recipient, 100));
+        assert_eq!(last_xcm_transfer(), Some((vec![(asset_id, 100)], 0)));
+    });
+}
+
+#[test]
+fn it_works_messages_sent_to_origin_network() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let asset_id = [1; 32].into();
+        set_sora_decimals_metadata(asset_id);
+        let recipient = xcm::VersionedMultiLocation::V3(MultiLocation::new(
+            1,
+            X2(Parachain(666), GeneralKey { length: 32, data: test_general_key() }),
+        ));
+        set_call_origin_network(SubNetworkId::Kusama);
+
+        assert_ok!(XCMApp::transfer(
+            RuntimeOrigin::root(),
+            asset_id,
+            alice(),
+            recipient.clone(),
+            100
+        ));
+        assert_eq!(last_submitted_network(), Some(SubNetworkId::Kusama));
+
+        // refund is sent to the origin network too
+        assert_ok!(XCMApp::set_asset_paused(RuntimeOrigin::root(), asset_id, true));
+        assert_ok!(XCMApp::transfer(RuntimeOrigin::root(), asset_id, alice(), recipient, 100));
+        assert_eq!(last_submitted_network(), Some(SubNetworkId::Kusama));
+
+        // dust of the transfer is refunded to the origin network
+        let dust_asset_id = [3; 32].into();
+        crate::Metadata::<Test>::insert(
+            dust_asset_id,
+            AssetMetadata {
+                name: b"Token".to_vec().try_into().unwrap(),
+                symbol: b"TKN".to_vec().try_into().unwrap(),
+                decimals: 12,
+                existential_deposit: 0,
+            },
+        );
+        let submitted = submitted_networks().len();
+        assert_ok!(XCMApp::transfer(
+            RuntimeOrigin::root(),
+            dust_asset_id,
+            alice(),
+            recipient.clone(),
+            5_000_123
+        ));
+        System::assert_has_event(RuntimeEvent::XCMApp(crate::Event::DustRefunded(
+            alice(),
+            dust_asset_id,
+            123,
+        )));
+        assert_eq!(submitted_networks()[submitted..], [SubNetworkId::Kusama, SubNetworkId::Kusama]);
+
+        // locally originated messages are sent to the default network
+        set_sora_decimals_metadata([2; 32].into());
+        assert_ok!(<XCMApp as MultiCurrency<_>>::deposit([2; 32].into(), &alice(), 100));
+        assert_eq!(last_submitted_network(), Some(SubNetworkId::Mainnet));
+
+        // trapped messages are resent to the network they belong to
+        XCMApp::trap_asset(SubNetworkId::Kusama, None, asset_id, alice(), 10, false);
+        assert_ok!(XCMApp::do_claim_bridge_asset(XCMApp::bridge_asset_trap_nonce()));
+        assert_eq!(last_submitted_network(), Some(SubNetworkId::Kusama));
+        crate::TrappedDoneResult::<Test>::insert(
+            H256::repeat_byte(1),
+            crate::TrappedDoneResultInfo {
+                network_id: SubNetworkId::Kusama,
+                sender: Some(alice()),
+            },
+        );
+        assert_ok!(XCMApp::resend_trapped_done_result(RuntimeOrigin::root(), H256::repeat_byte(1)));
+        assert_eq!(last_submitted_network(), Some(SubNetworkId::Kusama));
+    });
+}
+
+#[test]
+fn it_works_receive_and_send_sora_native_asset() {
+    new_test_ext().execute_with(|| {
+        let asset_id = [1; 32].into();
+        let sidechain_asset_id = [4; 32].into();
+        let recipient = xcm::VersionedMultiLocation::V3(
+            xcm::v3::MultiLocation::parent()
+                .pushed_with_interior(xcm::v3::Junction::Parachain(SELF_PARA_ID))
+                .unwrap()
+                .pushed_with_interior(xcm::v3::Junction::AccountId32 {
+                    network: None,
+                    id: bob().into(),
+                })
+                .unwrap(),
+        );
+        assert_ok!(XCMApp::register_asset(
+            RuntimeOrigin::root(),
+            asset_id,
+            MultiLocation::parent().into(),
+            AssetKind::Thischain,
+            1000
+        ));
+        assert_ok!(XCMApp::register_asset(
+            RuntimeOrigin::root(),
+            sidechain_asset_id,
+            MultiLocation::new(1, X1(Parachain(666))).into(),
+            AssetKind::Sidechain,
+            1000
+        ));
+        assert!(XCMApp::sora_native_asset(asset_id).is_some());
+        assert!(XCMApp::sora_native_asset(sidechain_asset_id).is_none());
+
+        assert_ok!(XCMApp::xcm_transfer_asset(
+            SubNetworkId::Mainnet,
+            asset_id,
+            alice(),
+            recipient.clone(),
+            1000000
+        ));
+        assert_eq!(Tokens::free_balance(asset_id, &bob()), 1000000);
+        assert_err!(
+            XCMApp::xcm_transfer_asset(
+                SubNetworkId::Mainnet,
+                sidechain_asset_id,
+                alice(),
+                recipient,
+                1000000
+            ),
+            Error::<Test>::InvalidAssetId
+        );
+        assert_eq!(Tokens::free_balance(sidechain_asset_id, &bob()), 0);
+
+        assert_ok!(XCMApp::send_to_mainnet(
+            RuntimeOrigin::signed(bob()),
+            asset_id,
+            alice(),
+            400000
+        ));
+        assert_eq!(Tokens::free_balance(asset_id, &bob()), 600000);
+        assert_noop!(
+            XCMApp::send_to_mainnet(RuntimeOrigin::signed(bob()), asset_id, alice(), 700000),
+            orml_tokens::Error::<Test>::BalanceTooLow
+        );
+        assert_noop!(
+            XCMApp::send_to_mainnet(RuntimeOrigin::signed(bob()), sidechain_asset_id, alice(), 1),
+            Error::<Test>::AssetNotSoraNative
+        );
+
+        // local balances stay withdrawable after the mapping is deleted
+        assert_ok!(XCMApp::delete_mapping(RuntimeOrigin::root(), asset_id));
+        assert!(XCMApp::sora_native_asset(asset_id).is_some());
+    });
+}
+
+#[test]
+fn it_works_set_sora_native_asset() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let asset_id = [5; 32].into();
+        let recipient = xcm::VersionedMultiLocation::V3(
+            xcm::v3::MultiLocation::parent()
+                .pushed_with_interior(xcm::v3::Junction::Parachain(SELF_PARA_ID))
+                .unwrap()
+                .pushed_with_interior(xcm::v3::Junction::AccountId32 {
+                    network: None,
+                    id: bob().into(),
+                })
+                .unwrap(),
+        );
+        assert_noop!(
+            XCMApp::set_sora_native_asset(RuntimeOrigin::signed(alice()), asset_id, true),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(XCMApp::set_sora_native_asset(RuntimeOrigin::root(), asset_id, true));
+        System::assert_last_event(RuntimeEvent::XCMApp(crate::Event::SoraNativeAssetSet(
+            asset_id, true,
+        )));
+
+        // minted on receive and burned on send
+        assert_ok!(XCMApp::xcm_transfer_asset(
+            SubNetworkId::Mainnet,
+            asset_id,
+            alice(),
+            recipient,
+            1000
+        ));
+        assert_eq!(Tokens::total_issuance(asset_id), 1000);
+        assert_noop!(
+            XCMApp::set_sora_native_asset(RuntimeOrigin::root(), asset_id, false),
+            Error::<Test>::LocalAssetInUse
+        );
+        assert_ok!(XCMApp::send_to_mainnet(RuntimeOrigin::signed(bob()), asset_id, alice(), 1000));
+        assert_eq!(Tokens::total_issuance(asset_id), 0);
+
+        assert_ok!(XCMApp::set_sora_native_asset(RuntimeOrigin::root(), asset_id, false));
+        assert!(XCMApp::sora_native_asset(asset_id).is_none());
+    });
+}
+
+#[test]
+fn it_refunds_xor_below_existential_deposit() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        ExistentialDeposit::set(&100);
+        let asset_id = XorAssetId::get();
+        let recipient = xcm::VersionedMultiLocation::V3(
+            xcm::v3::MultiLocation::parent()
+                .pushed_with_interior(xcm::v3::Junction::Parachain(SELF_PARA_ID))
+                .unwrap()
+                .pushed_with_interior(xcm::v3::Junction::AccountId32 {
+                    network: None,
+                    id: bob().into(),
+                })
+                .unwrap(),
+        );
+        assert_err!(
+            XCMApp::xcm_transfer_asset(
+                SubNetworkId::Mainnet,
+                asset_id,
+                alice(),
+                recipient.clone(),
+                99
+            ),
+            Error::<Test>::BelowExistentialDeposit
+        );
+        assert_eq!(Balances::total_balance(&bob()), 0);
+
+        // transfer is refunded
+        assert_ok!(XCMApp::transfer(
+            RuntimeOrigin::root(),
+            asset_id,
+            alice(),
+            recipient.clone(),
+            99
+        ));
+        System::assert_last_event(RuntimeEvent::XCMApp(crate::Event::AssetRefundSent(
+            [1; 32].into(),
+            alice(),
+            asset_id,
+            99,
+        )));
+
+        assert_ok!(XCMApp::xcm_transfer_asset(
+            SubNetworkId::Mainnet,
+            asset_id,
+            alice(),
+            recipient.clone(),
+            100
+        ));
+        System::assert_has_event(RuntimeEvent::XCMApp(crate::Event::AssetDeposited(
+            bob(),
+            asset_id,
+            100,
+        )));
+        // existing account can receive any amount
+        assert_ok!(XCMApp::xcm_transfer_asset(
+            SubNetworkId::Mainnet,
+            asset_id,
+            alice(),
+            recipient,
+            1
+        ));
+        assert_eq!(Balances::total_balance(&bob()), 101);
+    });
+}
+
+#[test]
+fn it_works_asset_metadata() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let asset_id = [1; 32].into();
+        let multilocation = MultiLocation::new(1, X1(Parachain(666)));
+        let metadata = AssetMetadata {
+            name: b"Token".to_vec().try_into().unwrap(),
+            symbol: b"TKN".to_vec().try_into().unwrap(),
+            decimals: 12,
+            existential_deposit: 1000,
+        };
+        assert_noop!(
+            XCMApp::set_asset_metadata(RuntimeOrigin::root(), asset_id, Some(metadata.clone())),
+            Error::<Test>::MappingNotExist
+        );
+        assert_ok!(XCMApp::register_asset(
+            RuntimeOrigin::root(),
+            asset_id,
+            multilocation.into(),
+            AssetKind::Sidechain,
+            100000,
+        ));
+        assert_eq!(XCMApp::asset_metadata(asset_id), None);
+        assert_noop!(
+            XCMApp::set_asset_metadata(
+                RuntimeOrigin::signed(alice()),
+                asset_id,
+                Some(metadata.clone())
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(XCMApp::set_asset_metadata(
+            RuntimeOrigin::root(),
+            asset_id,
+            Some(metadata.clone())
+        ));
+        assert_eq!(XCMApp::asset_metadata(asset_id), Some(metadata.clone()));
+
+        let new_metadata = AssetMetadata { decimals: 18, ..metadata };
+        assert_ok!(XCMApp::set_asset_metadata(
+            RuntimeOrigin::root(),
+            asset_id,
+            Some(new_metadata.clone())
+        ));
+        assert_eq!(XCMApp::asset_metadata(asset_id), Some(new_metadata.clone()));
+        System::assert_last_event(RuntimeEvent::XCMApp(crate::Event::AssetMetadataSet(
+            asset_id,
+            Some(new_metadata),
+        )));
+
+        assert_ok!(XCMApp::delete_mapping(RuntimeOrigin::root(), asset_id));
+        assert_eq!(XCMApp::asset_metadata(asset_id), None);
+    });
+}
+
+#[test]
+fn it_works_asset_decimals_conversion() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let asset_id = [1; 32].into();
+        let recipient = xcm::VersionedMultiLocation::V3(MultiLocation::new(
+            1,
+            X2(Parachain(666), GeneralKey { length: 32, data: test_general_key() }),
+        ));
+        let metadata = |decimals| AssetMetadata {
+            name: b"Token".to_vec().try_into().unwrap(),
+            symbol: b"TKN".to_vec().try_into().unwrap(),
+            decimals,
+            existential_deposit: 0,
+        };
+        assert_ok!(XCMApp::register_asset(
+            RuntimeOrigin::root(),
+            asset_id,
+            MultiLocation::new(1, X1(Parachain(666))).into(),
+            AssetKind::Sidechain,
+            1,
+        ));
+        assert_ok!(XCMApp::set_asset_metadata(RuntimeOrigin::root(), asset_id, Some(metadata(12))));
+        assert_eq!(XCMApp::asset_decimals(asset_id), Some(DecimalsPair { sora: 18, foreign: 12 }));
+
+        // outbound amount is converted to foreign decimals, the dust is sent back to the sender
+        assert_ok!(XCMApp::transfer(
+            RuntimeOrigin::root(),
+            asset_id,
+            alice(),
+            recipient.clone(),
+            5_000_123
+        ));
+        assert_eq!(last_xcm_transfer(), Some((vec![(asset_id, 5)], 0)));
+        System::assert_has_event(RuntimeEvent::XCMApp(crate::Event::DustRefunded(
+            alice(),
+            asset_id,
+            123,
+        )));
+        // the refunded dust is not a new supply
+        assert_eq!(XCMApp::asset_accounting(asset_id).deposited, 0);
+        assert_eq!(XCMApp::asset_accounting(asset_id).returned, 123);
+
+        // outbound amount below one foreign unit is refunded
+        assert_ok!(XCMApp::transfer(
+            RuntimeOrigin::root(),
+            asset_id,
+            alice(),
+            recipient.clone(),
+            999_999
+        ));
+        assert_eq!(last_xcm_transfer(), Some((vec![(asset_id, 5)], 0)));
+        System::assert_has_event(RuntimeEvent::XCMApp(crate::Event::AssetRefundSent(
+            [1; 32].into(),
+            alice(),
+            asset_id,
+            999_999,
+        )));
+
+        // inbound amount is converted to Sora decimals
+        assert_ok!(<XCMApp as MultiCurrency<_>>::deposit(asset_id, &alice(), 7));
+        assert_eq!(XCMApp::asset_accounting(asset_id).deposited, 7_000_000);
+
+        // inbound dust which can not be represented on Sora is trapped
+        assert_ok!(XCMApp::set_asset_metadata(RuntimeOrigin::root(), asset_id, Some(metadata(20))));
+        assert_ok!(<XCMApp as MultiCurrency<_>>::deposit(asset_id, &alice(), 1_042));
+        assert_eq!(XCMApp::asset_accounting(asset_id).deposited, 7_000_010);
+        assert_eq!(XCMApp::asset_dust(asset_id), 42);
+        System::assert_has_event(RuntimeEvent::XCMApp(crate::Event::DustTrapped(asset_id, 42)));
+        assert_noop!(
+            <XCMApp as MultiCurrency<_>>::deposit(asset_id, &alice(), 99),
+            Error::<Test>::AmountTooSmall
+        );
+
+        // withdrawn remainder is covered by the trapped dust
+        assert_ok!(<XCMApp as MultiCurrency<_>>::withdraw(asset_id, &alice(), 1_030));
+        assert_eq!(XCMApp::asset_accounting(asset_id).withdrawn, 10);
+        assert_eq!(XCMApp::asset_dust(asset_id), 12);
+        // otherwise it is rounded up, the excess is trapped
+        assert_ok!(<XCMApp as MultiCurrency<_>>::withdraw(asset_id, &alice(), 50));
+        assert_eq!(XCMApp::asset_accounting(asset_id).withdrawn, 11);
+        assert_eq!(XCMApp::asset_dust(asset_id), 62);
+
+        // trapped dust is claimed once it makes up a unit on Sora
+        assert_noop!(
+            XCMApp::claim_asset_dust(RuntimeOrigin::signed(alice()), asset_id, bob()),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            XCMApp::claim_asset_dust(RuntimeOrigin::root(), asset_id, bob()),
+            Error::<Test>::AmountTooSmall
+        );
+        assert_ok!(<XCMApp as MultiCurrency<_>>::deposit(asset_id, &alice(), 1_038));
+        assert_eq!(XCMApp::asset_dust(asset_id), 100);
+        assert_ok!(XCMApp::claim_asset_dust(RuntimeOrigin::root(), asset_id, bob()));
+        assert_eq!(XCMApp::asset_dust(asset_id), 0);
+        assert_eq!(XCMApp::asset_accounting(asset_id).deposited, 7_000_021);
+        System::assert_last_event(RuntimeEvent::XCMApp(crate::Event::DustClaimed(
+            bob(),
+            asset_id,
+            1,
+        )));
+        assert_ok!(XCMApp::do_try_state());
+
+        // amounts can not be converted without metadata
+        assert_ok!(XCMApp::set_asset_metadata(RuntimeOrigin::root(), asset_id, None));
+        assert_eq!(XCMApp::asset_decimals(asset_id), None);
+        assert_noop!(
+            <XCMApp as MultiCurrency<_>>::deposit(asset_id, &alice(), 100),
+            Error::<Test>::MetadataNotExist
+        );
+        assert_ok!(XCMApp::transfer(RuntimeOrigin::root(), asset_id, alice(), recipient, 100));
+        System::assert_last_event(RuntimeEvent::XCMApp(crate::Event::AssetRefundSent(
+            [1; 32].into(),
+            alice(),
+            asset_id,
+            100,
+        )));
+    });
+}
+
+#[test]
+fn it_reverts_failed_transfer_accounting() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let asset_id = [1; 32].into();
+        let recipient = xcm::VersionedMultiLocation::V3(MultiLocation::new(
+            1,
+            X2(Parachain(666), GeneralKey { length: 32, data: test_general_key() }),
+        ));
+        assert_ok!(XCMApp::register_asset(
+            RuntimeOrigin::root(),
+            asset_id,
+            MultiLocation::new(1, X1(Parachain(666))).into(),
+            AssetKind::Sidechain,
+            1,
+        ));
+        assert_ok!(XCMApp::set_asset_metadata(
+            RuntimeOrigin::root(),
+            asset_id,
+            Some(AssetMetadata {
+                name: b"Token".to_vec().try_into().unwrap(),
+                symbol: b"TKN".to_vec().try_into().unwrap(),
+                decimals: 12,
+                existential_deposit: 0,
+            })
+        ));
+
+        set_xcm_transfer_fails(true);
+        assert_ok!(XCMApp::transfer(
+            RuntimeOrigin::root(),
+            asset_id,
+            alice(),
+            recipient,
+            5_000_123
+        ));
+        assert!(System::events().iter().any(|r| matches!(
+            r.event,
+            RuntimeEvent::XCMApp(crate::Event::TrasferringAssetError(_, id)) if id == asset_id
+        )));
+        // neither the withdrawn amount nor the dust refund is left behind the refund
+        assert_eq!(XCMApp::asset_accounting(asset_id), Default::default());
+        assert_eq!(XCMApp::asset_transfer_volume(asset_id), Default::default());
+        assert!(!System::events()
+            .iter()
+            .any(|r| matches!(r.event, RuntimeEvent::XCMApp(crate::Event::DustRefunded(..)))));
+    });
+}
+
+#[test]
+fn it_works_asset_fee_per_second() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let asset_id = [1; 32].into();
+        let multilocation = MultiLocation::new(1, X1(Parachain(666)));
+        assert_noop!(
+            XCMApp::set_asset_fee_per_second(RuntimeOrigin::root(), asset_id, Some(1000)),
+            Error::<Test>::MappingNotExist
+        );
+        assert_ok!(XCMApp::register_asset(
+            RuntimeOrigin::root(),
+            asset_id,
+            multilocation.into(),
+            AssetKind::Sidechain,
+            1,
+        ));
+        assert_noop!(
+            XCMApp::set_asset_fee_per_second(RuntimeOrigin::signed(alice()), asset_id, Some(1000)),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_eq!(XCMApp::fee_for_weight(&multilocation, Weight::from_parts(1_000_000, 0)), None);
+
+        assert_ok!(XCMApp::set_asset_fee_per_second(RuntimeOrigin::root(), asset_id, Some(1000)));
+        System::assert_last_event(RuntimeEvent::XCMApp(crate::Event::AssetFeePerSecondSet(
+            asset_id,
+            Some(1000),
+        )));
+        assert_eq!(XCMApp::asset_fee_per_second(multilocation), Some(1000));
+        assert_eq!(
+            XCMApp::fee_for_weight(&multilocation, Weight::from_parts(500_000_000_000, 0)),
+            Some(500)
+        );
+        assert_eq!(
+            XCMApp::quote_fee(asset_id, Weight::from_parts(2_000_000_000_000, 0)),
+            Some(2000)
+        );
+        assert_eq!(XCMApp::quote_fee([2; 32].into(), Weight::from_parts(1_000_000, 0)), None);
+
+        // fee is moved along with the mapping
+        let new_multilocation = MultiLocation::new(1, X1(Parachain(777)));
+        assert_ok!(XCMApp::change_asset_mapping(
+            RuntimeOrigin::root(),
+            asset_id,
+            new_multilocation.into()
+        ));
+        assert_eq!(XCMApp::asset_fee_per_second(multilocation), None);
+        assert_eq!(XCMApp::asset_fee_per_second(new_multilocation), Some(1000));
+
+        assert_ok!(XCMApp::delete_mapping(RuntimeOrigin::root(), asset_id));
+        assert_eq!(XCMApp::asset_fee_per_second(new_multilocation), None);
+    });
+}
+
+#[test]
+fn it_works_trusted_xcm_origins() {
+    use xcm_executor::traits::ShouldExecute;
+    type Barrier = crate::ReportUntrustedOrigin<
+        xcm_builder::AllowTopLevelPaidExecutionFrom<crate::TrustedXcmOrigin<Test>>,
+        xcm_builder::AllowTopLevelPaidExecutionFrom<frame_support::traits::Everything>,
+        Test,
+    >;
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let sibling = MultiLocation::new(1, X1(Parachain(666)));
+        let should_execute = |origin: &MultiLocation| {
+            let fees: xcm::v3::MultiAsset = (MultiLocation::parent(), 1_000).into();
+            let mut instructions = [
+                xcm::v3::Instruction::<()>::WithdrawAsset(fees.clone().into()),
+                xcm::v3::Instruction::<()>::BuyExecution {
+                    fees,
+                    weight_limit: xcm::v3::WeightLimit::Unlimited,
+                },
+            ];
+            Barrier::should_execute(origin, &mut instructions, Weight::MAX, &mut Weight::zero())
+        };
+
+        // message rejected for other reasons than the origin is not reported
+        let mut instructions = [xcm::v3::Instruction::<()>::ClearOrigin];
+        assert_eq!(
+            Barrier::should_execute(&sibling, &mut instructions, Weight::MAX, &mut Weight::zero()),
+            Err(())
+        );
+        assert!(System::events().is_empty());
+
+        assert_eq!(should_execute(&sibling), Err(()));
+        System::assert_last_event(RuntimeEvent::XCMApp(crate::Event::XcmOriginRejected(sibling)));
+
+        // the origin passes the bridge CallOrigin in the mock, but only ManageOrigin is allowed
+        assert_noop!(
+            XCMApp::set_trusted_xcm_origin(RuntimeOrigin::signed(alice()), sibling.into(), true),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(XCMApp::set_trusted_xcm_origin(RuntimeOrigin::root(), sibling.into(), true));
+        System::assert_last_event(RuntimeEvent::XCMApp(crate::Event::TrustedXcmOriginSet(
+            sibling, true,
+        )));
+        assert_eq!(XCMApp::trusted_xcm_origin(sibling), Some(()));
+        System::reset_events();
+        assert_eq!(should_execute(&sibling), Ok(()));
+        assert!(System::events().is_empty());
+
+        assert_ok!(XCMApp::set_trusted_xcm_origin(RuntimeOrigin::root(), sibling.into(), false));
+        assert_eq!(XCMApp::trusted_xcm_origin(sibling), None);
+        assert_eq!(should_execute(&sibling), Err(()));
+    });
+}
+
+#[test]
+fn it_works_transact_allowed_calls() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let sibling = MultiLocation::new(1, X1(Parachain(666)));
+        let call_index = (1, 2);
+
+        // the bridge CallOrigin can not allow calls, only ManageOrigin can
+        assert_ok!(XCMApp::ensure_manage_origin(RuntimeOrigin::signed(alice())));
+        assert_noop!(
+            XCMApp::set_transact_call_allowed(
+                RuntimeOrigin::signed(alice()),
+                sibling.into(),
+                call_index,
+                true
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(XCMApp::set_transact_call_allowed(
+            RuntimeOrigin::root(),
+            sibling.into(),
+            call_index,
+            true
+        ));
+        System::assert_last_event(RuntimeEvent::XCMApp(crate::Event::TransactCallAllowedSet(
+            sibling, call_index, true,
+        )));
+        assert_eq!(XCMApp::transact_call_allowed(sibling, call_index), Some(()));
+        // calls are allowed per origin
+        assert_eq!(XCMApp::transact_call_allowed(MultiLocation::parent(), call_index), None);
+        assert_eq!(XCMApp::transact_call_allowed(sibling, (1, 3)), None);
+
+        assert_ok!(XCMApp::set_transact_call_allowed(
+            RuntimeOrigin::root(),
+            sibling.into(),
+            call_index,
+            false
+        ));
+        assert_eq!(XCMApp::transact_call_allowed(sibling, call_index), None);
+    });
+}
@@ -35,6 +35,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `384709e9a18e`, CPU: `Intel(R) Xeon(R) Platinum 8275CL CPU @ 3.00GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("local"), DB CACHE: 1024
+//!
+//! Weights with `Estimated execution time` are not benchmark output: they are estimated
+//! from the storage accessed until the benchmarks are rerun. Estimates of `transfer` and
+//! `try_claim_bridge_asset` are the benchmarked weights scaled with the added storage items.
 
 // Executed Command:
 // ./target/release/parachain-collator
@@ -65,6 +69,22 @@ pub trait WeightInfo {
 	fn try_claim_bridge_asset() -> Weight;
 	fn set_asset_minimum_amount() -> Weight;
 	fn sudo_send_xcm() -> Weight;
+	fn resend_trapped_done_result() -> Weight;
+	fn set_asset_transfer_limit() -> Weight;
+	fn set_direction_paused() -> Weight;
+	fn set_asset_paused() -> Weight;
+	fn change_asset_mapping() -> Weight;
+	fn change_multilocation_mapping() -> Weight;
+	fn delete_mapping() -> Weight;
+	fn set_destination_weight_limit() -> Weight;
+	fn set_destination_fee() -> Weight;
+	fn send_to_mainnet() -> Weight;
+	fn set_sora_native_asset() -> Weight;
+	fn set_asset_metadata() -> Weight;
+	fn set_asset_fee_per_second() -> Weight;
+	fn set_trusted_xcm_origin() -> Weight;
+	fn claim_asset_dust() -> Weight;
+	fn set_transact_call_allowed() -> Weight;
 }
 
 /// Weights for xcm_app using the Substrate node and recommended hardware.
@@ -89,16 +109,34 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
-	/// Storage: XCMApp AssetIdToMultilocation (r:1 w:0)
+	/// Storage: XCMApp PausedDirections (r:1 w:0)
+	/// Proof Skipped: XCMApp PausedDirections (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp PausedAssets (r:1 w:0)
+	/// Proof Skipped: XCMApp PausedAssets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetTransferLimit (r:1 w:0)
+	/// Proof Skipped: XCMApp AssetTransferLimit (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetTransferVolume (r:1 w:1)
+	/// Proof Skipped: XCMApp AssetTransferVolume (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetIdToAbstract (r:1 w:0)
+	/// Proof Skipped: XCMApp AssetIdToAbstract (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp Metadata (r:1 w:0)
+	/// Proof Skipped: XCMApp Metadata (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetIdToMultilocation (r:2 w:0)
 	/// Proof Skipped: XCMApp AssetIdToMultilocation (max_values: None, max_size: None, mode: Measured)
 	/// Storage: ParachainInfo ParachainId (r:1 w:0)
 	/// Proof: ParachainInfo ParachainId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: XCMApp DestinationFees (r:1 w:0)
+	/// Proof Skipped: XCMApp DestinationFees (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp DestinationWeightLimit (r:1 w:0)
+	/// Proof Skipped: XCMApp DestinationWeightLimit (max_values: None, max_size: None, mode: Measured)
 	/// Storage: XCMApp MultilocationToAssetId (r:1 w:0)
 	/// Proof Skipped: XCMApp MultilocationToAssetId (max_values: None, max_size: None, mode: Measured)
 	/// Storage: ParachainSystem HostConfiguration (r:1 w:0)
 	/// Proof Skipped: ParachainSystem HostConfiguration (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
 	/// Proof Skipped: ParachainSystem PendingUpwardMessages (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: XCMApp Accounting (r:1 w:1)
+	/// Proof Skipped: XCMApp Accounting (max_values: None, max_size: None, mode: Measured)
 	/// Storage: SubstrateBridgeOutboundChannel MessageQueues (r:1 w:1)
 	/// Proof Skipped: SubstrateBridgeOutboundChannel MessageQueues (max_values: None, max_size: None, mode: Measured)
 	/// Storage: SubstrateBridgeOutboundChannel ChannelNonces (r:1 w:0)
@@ -106,14 +144,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn transfer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `694`
-		//  Estimated: `15553`
-		// Minimum execution time: 151_435_000 picoseconds.
-		Weight::from_parts(154_214_000, 15553)
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		//  Estimated: `45553`
+		// Estimated execution time: 190_214_000 picoseconds.
+		Weight::from_parts(190_214_000, 45553)
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: XCMApp BridgeAssetTrap (r:1 w:1)
 	/// Proof Skipped: XCMApp BridgeAssetTrap (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp Accounting (r:1 w:1)
+	/// Proof Skipped: XCMApp Accounting (max_values: None, max_size: None, mode: Measured)
 	/// Storage: SubstrateBridgeOutboundChannel MessageQueues (r:1 w:1)
 	/// Proof Skipped: SubstrateBridgeOutboundChannel MessageQueues (max_values: None, max_size: None, mode: Measured)
 	/// Storage: SubstrateBridgeOutboundChannel ChannelNonces (r:1 w:0)
@@ -121,11 +161,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn try_claim_bridge_asset() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `276`
-		//  Estimated: `8253`
-		// Minimum execution time: 42_367_000 picoseconds.
-		Weight::from_parts(43_245_000, 8253)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		//  Estimated: `11253`
+		// Estimated execution time: 49_245_000 picoseconds.
+		Weight::from_parts(49_245_000, 11253)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: XCMApp AssetIdToMultilocation (r:1 w:0)
 	/// Proof Skipped: XCMApp AssetIdToMultilocation (max_values: None, max_size: None, mode: Measured)
@@ -153,6 +193,245 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: XCMApp TrappedDoneResult (r:1 w:1)
+	/// Proof Skipped: XCMApp TrappedDoneResult (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SubstrateBridgeOutboundChannel MessageQueues (r:1 w:1)
+	/// Proof Skipped: SubstrateBridgeOutboundChannel MessageQueues (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SubstrateBridgeOutboundChannel ChannelNonces (r:1 w:0)
+	/// Proof Skipped: SubstrateBridgeOutboundChannel ChannelNonces (max_values: None, max_size: None, mode: Measured)
+	fn resend_trapped_done_result() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `221`
+		//  Estimated: `8088`
+		// Estimated execution time: 30_000_000 picoseconds.
+		Weight::from_parts(30_000_000, 8088)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: XCMApp AssetTransferLimit (r:0 w:1)
+	/// Proof Skipped: XCMApp AssetTransferLimit (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetTransferVolume (r:0 w:1)
+	/// Proof Skipped: XCMApp AssetTransferVolume (max_values: None, max_size: None, mode: Measured)
+	fn set_asset_transfer_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Estimated execution time: 21_000_000 picoseconds.
+		Weight::from_parts(21_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: XCMApp PausedDirections (r:0 w:1)
+	/// Proof Skipped: XCMApp PausedDirections (max_values: None, max_size: None, mode: Measured)
+	fn set_direction_paused() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Estimated execution time: 18_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: XCMApp PausedAssets (r:0 w:1)
+	/// Proof Skipped: XCMApp PausedAssets (max_values: None, max_size: None, mode: Measured)
+	fn set_asset_paused() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Estimated execution time: 18_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: XCMApp AssetIdToMultilocation (r:1 w:1)
+	/// Proof Skipped: XCMApp AssetIdToMultilocation (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp MultilocationToAssetId (r:1 w:2)
+	/// Proof Skipped: XCMApp MultilocationToAssetId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetMinimumAmount (r:1 w:2)
+	/// Proof Skipped: XCMApp AssetMinimumAmount (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetFeePerSecond (r:1 w:2)
+	/// Proof Skipped: XCMApp AssetFeePerSecond (max_values: None, max_size: None, mode: Measured)
+	fn change_asset_mapping() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6762`
+		//  Estimated: `9762`
+		// Estimated execution time: 48_000_000 picoseconds.
+		Weight::from_parts(48_000_000, 9762)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: XCMApp MultilocationToAssetId (r:1 w:1)
+	/// Proof Skipped: XCMApp MultilocationToAssetId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetIdToMultilocation (r:1 w:2)
+	/// Proof Skipped: XCMApp AssetIdToMultilocation (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp Accounting (r:1 w:0)
+	/// Proof Skipped: XCMApp Accounting (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetDust (r:1 w:0)
+	/// Proof Skipped: XCMApp AssetDust (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp Metadata (r:1 w:2)
+	/// Proof Skipped: XCMApp Metadata (max_values: None, max_size: None, mode: Measured)
+	fn change_multilocation_mapping() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3853`
+		//  Estimated: `6853`
+		// Estimated execution time: 30_000_000 picoseconds.
+		Weight::from_parts(45_000_000, 6853)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: XCMApp Accounting (r:1 w:0)
+	/// Proof Skipped: XCMApp Accounting (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetDust (r:1 w:0)
+	/// Proof Skipped: XCMApp AssetDust (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetIdToMultilocation (r:1 w:1)
+	/// Proof Skipped: XCMApp AssetIdToMultilocation (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp MultilocationToAssetId (r:0 w:1)
+	/// Proof Skipped: XCMApp MultilocationToAssetId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetMinimumAmount (r:0 w:1)
+	/// Proof Skipped: XCMApp AssetMinimumAmount (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetFeePerSecond (r:0 w:1)
+	/// Proof Skipped: XCMApp AssetFeePerSecond (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp Metadata (r:0 w:1)
+	/// Proof Skipped: XCMApp Metadata (max_values: None, max_size: None, mode: Measured)
+	fn delete_mapping() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `441`
+		//  Estimated: `3441`
+		// Estimated execution time: 33_000_000 picoseconds.
+		Weight::from_parts(39_000_000, 3441)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: XCMApp DestinationWeightLimit (r:0 w:1)
+	/// Proof Skipped: XCMApp DestinationWeightLimit (max_values: None, max_size: None, mode: Measured)
+	fn set_destination_weight_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Estimated execution time: 18_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: XCMApp DestinationFees (r:0 w:1)
+	/// Proof Skipped: XCMApp DestinationFees (max_values: None, max_size: None, mode: Measured)
+	fn set_destination_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Estimated execution time: 18_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: XCMApp PausedDirections (r:1 w:0)
+	/// Proof Skipped: XCMApp PausedDirections (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp PausedAssets (r:1 w:0)
+	/// Proof Skipped: XCMApp PausedAssets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetTransferLimit (r:1 w:0)
+	/// Proof Skipped: XCMApp AssetTransferLimit (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetTransferVolume (r:1 w:1)
+	/// Proof Skipped: XCMApp AssetTransferVolume (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp SoraNativeAssets (r:1 w:0)
+	/// Proof Skipped: XCMApp SoraNativeAssets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: XCMApp Accounting (r:1 w:1)
+	/// Proof Skipped: XCMApp Accounting (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SubstrateBridgeOutboundChannel MessageQueues (r:1 w:1)
+	/// Proof Skipped: SubstrateBridgeOutboundChannel MessageQueues (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SubstrateBridgeOutboundChannel ChannelNonces (r:1 w:0)
+	/// Proof Skipped: SubstrateBridgeOutboundChannel ChannelNonces (max_values: None, max_size: None, mode: Measured)
+	fn send_to_mainnet() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1236`
+		//  Estimated: `19356`
+		// Estimated execution time: 60_000_000 picoseconds.
+		Weight::from_parts(60_000_000, 19356)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: Tokens TotalIssuance (r:1 w:0)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: XCMApp SoraNativeAssets (r:0 w:1)
+	/// Proof Skipped: XCMApp SoraNativeAssets (max_values: None, max_size: None, mode: Measured)
+	fn set_sora_native_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `3529`
+		// Estimated execution time: 21_000_000 picoseconds.
+		Weight::from_parts(21_000_000, 3529)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: XCMApp AssetIdToMultilocation (r:1 w:0)
+	/// Proof Skipped: XCMApp AssetIdToMultilocation (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetIdToAbstract (r:1 w:0)
+	/// Proof Skipped: XCMApp AssetIdToAbstract (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp Metadata (r:0 w:1)
+	/// Proof Skipped: XCMApp Metadata (max_values: None, max_size: None, mode: Measured)
+	fn set_asset_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1171`
+		//  Estimated: `4171`
+		// Estimated execution time: 24_000_000 picoseconds.
+		Weight::from_parts(24_000_000, 4171)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: XCMApp AssetIdToMultilocation (r:1 w:0)
+	/// Proof Skipped: XCMApp AssetIdToMultilocation (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetFeePerSecond (r:0 w:1)
+	/// Proof Skipped: XCMApp AssetFeePerSecond (max_values: None, max_size: None, mode: Measured)
+	fn set_asset_fee_per_second() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1171`
+		//  Estimated: `4171`
+		// Estimated execution time: 21_000_000 picoseconds.
+		Weight::from_parts(21_000_000, 4171)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: XCMApp TrustedXcmOrigins (r:0 w:1)
+	/// Proof Skipped: XCMApp TrustedXcmOrigins (max_values: None, max_size: None, mode: Measured)
+	fn set_trusted_xcm_origin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Estimated execution time: 18_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: XCMApp PausedDirections (r:1 w:0)
+	/// Proof Skipped: XCMApp PausedDirections (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp PausedAssets (r:1 w:0)
+	/// Proof Skipped: XCMApp PausedAssets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetDust (r:1 w:1)
+	/// Proof Skipped: XCMApp AssetDust (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp Metadata (r:1 w:0)
+	/// Proof Skipped: XCMApp Metadata (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp Accounting (r:1 w:1)
+	/// Proof Skipped: XCMApp Accounting (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SubstrateBridgeOutboundChannel MessageQueues (r:1 w:1)
+	/// Proof Skipped: SubstrateBridgeOutboundChannel MessageQueues (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SubstrateBridgeOutboundChannel ChannelNonces (r:1 w:0)
+	/// Proof Skipped: SubstrateBridgeOutboundChannel ChannelNonces (max_values: None, max_size: None, mode: Measured)
+	fn claim_asset_dust() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3148`
+		//  Estimated: `6148`
+		// Estimated execution time: 45_000_000 picoseconds.
+		Weight::from_parts(45_000_000, 6148)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: XCMApp TransactAllowedCalls (r:0 w:1)
+	/// Proof Skipped: XCMApp TransactAllowedCalls (max_values: None, max_size: None, mode: Measured)
+	fn set_transact_call_allowed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Estimated execution time: 18_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -176,16 +455,34 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
-	/// Storage: XCMApp AssetIdToMultilocation (r:1 w:0)
+	/// Storage: XCMApp PausedDirections (r:1 w:0)
+	/// Proof Skipped: XCMApp PausedDirections (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp PausedAssets (r:1 w:0)
+	/// Proof Skipped: XCMApp PausedAssets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetTransferLimit (r:1 w:0)
+	/// Proof Skipped: XCMApp AssetTransferLimit (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetTransferVolume (r:1 w:1)
+	/// Proof Skipped: XCMApp AssetTransferVolume (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetIdToAbstract (r:1 w:0)
+	/// Proof Skipped: XCMApp AssetIdToAbstract (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp Metadata (r:1 w:0)
+	/// Proof Skipped: XCMApp Metadata (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetIdToMultilocation (r:2 w:0)
 	/// Proof Skipped: XCMApp AssetIdToMultilocation (max_values: None, max_size: None, mode: Measured)
 	/// Storage: ParachainInfo ParachainId (r:1 w:0)
 	/// Proof: ParachainInfo ParachainId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: XCMApp DestinationFees (r:1 w:0)
+	/// Proof Skipped: XCMApp DestinationFees (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp DestinationWeightLimit (r:1 w:0)
+	/// Proof Skipped: XCMApp DestinationWeightLimit (max_values: None, max_size: None, mode: Measured)
 	/// Storage: XCMApp MultilocationToAssetId (r:1 w:0)
 	/// Proof Skipped: XCMApp MultilocationToAssetId (max_values: None, max_size: None, mode: Measured)
 	/// Storage: ParachainSystem HostConfiguration (r:1 w:0)
 	/// Proof Skipped: ParachainSystem HostConfiguration (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
 	/// Proof Skipped: ParachainSystem PendingUpwardMessages (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: XCMApp Accounting (r:1 w:1)
+	/// Proof Skipped: XCMApp Accounting (max_values: None, max_size: None, mode: Measured)
 	/// Storage: SubstrateBridgeOutboundChannel MessageQueues (r:1 w:1)
 	/// Proof Skipped: SubstrateBridgeOutboundChannel MessageQueues (max_values: None, max_size: None, mode: Measured)
 	/// Storage: SubstrateBridgeOutboundChannel ChannelNonces (r:1 w:0)
@@ -193,14 +490,16 @@ impl WeightInfo for () {
 	fn transfer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `694`
-		//  Estimated: `15553`
-		// Minimum execution time: 151_435_000 picoseconds.
-		Weight::from_parts(154_214_000, 15553)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		//  Estimated: `45553`
+		// Estimated execution time: 190_214_000 picoseconds.
+		Weight::from_parts(190_214_000, 45553)
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: XCMApp BridgeAssetTrap (r:1 w:1)
 	/// Proof Skipped: XCMApp BridgeAssetTrap (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp Accounting (r:1 w:1)
+	/// Proof Skipped: XCMApp Accounting (max_values: None, max_size: None, mode: Measured)
 	/// Storage: SubstrateBridgeOutboundChannel MessageQueues (r:1 w:1)
 	/// Proof Skipped: SubstrateBridgeOutboundChannel MessageQueues (max_values: None, max_size: None, mode: Measured)
 	/// Storage: SubstrateBridgeOutboundChannel ChannelNonces (r:1 w:0)
@@ -208,11 +507,11 @@ impl WeightInfo for () {
 	fn try_claim_bridge_asset() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `276`
-		//  Estimated: `8253`
-		// Minimum execution time: 42_367_000 picoseconds.
-		Weight::from_parts(43_245_000, 8253)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		//  Estimated: `11253`
+		// Estimated execution time: 49_245_000 picoseconds.
+		Weight::from_parts(49_245_000, 11253)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: XCMApp AssetIdToMultilocation (r:1 w:0)
 	/// Proof Skipped: XCMApp AssetIdToMultilocation (max_values: None, max_size: None, mode: Measured)
@@ -240,4 +539,243 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: XCMApp TrappedDoneResult (r:1 w:1)
+	/// Proof Skipped: XCMApp TrappedDoneResult (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SubstrateBridgeOutboundChannel MessageQueues (r:1 w:1)
+	/// Proof Skipped: SubstrateBridgeOutboundChannel MessageQueues (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SubstrateBridgeOutboundChannel ChannelNonces (r:1 w:0)
+	/// Proof Skipped: SubstrateBridgeOutboundChannel ChannelNonces (max_values: None, max_size: None, mode: Measured)
+	fn resend_trapped_done_result() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `221`
+		//  Estimated: `8088`
+		// Estimated execution time: 30_000_000 picoseconds.
+		Weight::from_parts(30_000_000, 8088)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: XCMApp AssetTransferLimit (r:0 w:1)
+	/// Proof Skipped: XCMApp AssetTransferLimit (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetTransferVolume (r:0 w:1)
+	/// Proof Skipped: XCMApp AssetTransferVolume (max_values: None, max_size: None, mode: Measured)
+	fn set_asset_transfer_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Estimated execution time: 21_000_000 picoseconds.
+		Weight::from_parts(21_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: XCMApp PausedDirections (r:0 w:1)
+	/// Proof Skipped: XCMApp PausedDirections (max_values: None, max_size: None, mode: Measured)
+	fn set_direction_paused() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Estimated execution time: 18_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: XCMApp PausedAssets (r:0 w:1)
+	/// Proof Skipped: XCMApp PausedAssets (max_values: None, max_size: None, mode: Measured)
+	fn set_asset_paused() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Estimated execution time: 18_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: XCMApp AssetIdToMultilocation (r:1 w:1)
+	/// Proof Skipped: XCMApp AssetIdToMultilocation (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp MultilocationToAssetId (r:1 w:2)
+	/// Proof Skipped: XCMApp MultilocationToAssetId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetMinimumAmount (r:1 w:2)
+	/// Proof Skipped: XCMApp AssetMinimumAmount (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetFeePerSecond (r:1 w:2)
+	/// Proof Skipped: XCMApp AssetFeePerSecond (max_values: None, max_size: None, mode: Measured)
+	fn change_asset_mapping() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6762`
+		//  Estimated: `9762`
+		// Estimated execution time: 48_000_000 picoseconds.
+		Weight::from_parts(48_000_000, 9762)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: XCMApp MultilocationToAssetId (r:1 w:1)
+	/// Proof Skipped: XCMApp MultilocationToAssetId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetIdToMultilocation (r:1 w:2)
+	/// Proof Skipped: XCMApp AssetIdToMultilocation (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp Accounting (r:1 w:0)
+	/// Proof Skipped: XCMApp Accounting (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetDust (r:1 w:0)
+	/// Proof Skipped: XCMApp AssetDust (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp Metadata (r:1 w:2)
+	/// Proof Skipped: XCMApp Metadata (max_values: None, max_size: None, mode: Measured)
+	fn change_multilocation_mapping() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3853`
+		//  Estimated: `6853`
+		// Estimated execution time: 30_000_000 picoseconds.
+		Weight::from_parts(45_000_000, 6853)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: XCMApp Accounting (r:1 w:0)
+	/// Proof Skipped: XCMApp Accounting (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetDust (r:1 w:0)
+	/// Proof Skipped: XCMApp AssetDust (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetIdToMultilocation (r:1 w:1)
+	/// Proof Skipped: XCMApp AssetIdToMultilocation (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp MultilocationToAssetId (r:0 w:1)
+	/// Proof Skipped: XCMApp MultilocationToAssetId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetMinimumAmount (r:0 w:1)
+	/// Proof Skipped: XCMApp AssetMinimumAmount (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetFeePerSecond (r:0 w:1)
+	/// Proof Skipped: XCMApp AssetFeePerSecond (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp Metadata (r:0 w:1)
+	/// Proof Skipped: XCMApp Metadata (max_values: None, max_size: None, mode: Measured)
+	fn delete_mapping() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `441`
+		//  Estimated: `3441`
+		// Estimated execution time: 33_000_000 picoseconds.
+		Weight::from_parts(39_000_000, 3441)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: XCMApp DestinationWeightLimit (r:0 w:1)
+	/// Proof Skipped: XCMApp DestinationWeightLimit (max_values: None, max_size: None, mode: Measured)
+	fn set_destination_weight_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Estimated execution time: 18_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: XCMApp DestinationFees (r:0 w:1)
+	/// Proof Skipped: XCMApp DestinationFees (max_values: None, max_size: None, mode: Measured)
+	fn set_destination_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Estimated execution time: 18_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: XCMApp PausedDirections (r:1 w:0)
+	/// Proof Skipped: XCMApp PausedDirections (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp PausedAssets (r:1 w:0)
+	/// Proof Skipped: XCMApp PausedAssets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetTransferLimit (r:1 w:0)
+	/// Proof Skipped: XCMApp AssetTransferLimit (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetTransferVolume (r:1 w:1)
+	/// Proof Skipped: XCMApp AssetTransferVolume (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp SoraNativeAssets (r:1 w:0)
+	/// Proof Skipped: XCMApp SoraNativeAssets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: XCMApp Accounting (r:1 w:1)
+	/// Proof Skipped: XCMApp Accounting (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SubstrateBridgeOutboundChannel MessageQueues (r:1 w:1)
+	/// Proof Skipped: SubstrateBridgeOutboundChannel MessageQueues (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SubstrateBridgeOutboundChannel ChannelNonces (r:1 w:0)
+	/// Proof Skipped: SubstrateBridgeOutboundChannel ChannelNonces (max_values: None, max_size: None, mode: Measured)
+	fn send_to_mainnet() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1236`
+		//  Estimated: `19356`
+		// Estimated execution time: 60_000_000 picoseconds.
+		Weight::from_parts(60_000_000, 19356)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Tokens TotalIssuance (r:1 w:0)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: XCMApp SoraNativeAssets (r:0 w:1)
+	/// Proof Skipped: XCMApp SoraNativeAssets (max_values: None, max_size: None, mode: Measured)
+	fn set_sora_native_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `3529`
+		// Estimated execution time: 21_000_000 picoseconds.
+		Weight::from_parts(21_000_000, 3529)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: XCMApp AssetIdToMultilocation (r:1 w:0)
+	/// Proof Skipped: XCMApp AssetIdToMultilocation (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetIdToAbstract (r:1 w:0)
+	/// Proof Skipped: XCMApp AssetIdToAbstract (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp Metadata (r:0 w:1)
+	/// Proof Skipped: XCMApp Metadata (max_values: None, max_size: None, mode: Measured)
+	fn set_asset_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1171`
+		//  Estimated: `4171`
+		// Estimated execution time: 24_000_000 picoseconds.
+		Weight::from_parts(24_000_000, 4171)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: XCMApp AssetIdToMultilocation (r:1 w:0)
+	/// Proof Skipped: XCMApp AssetIdToMultilocation (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetFeePerSecond (r:0 w:1)
+	/// Proof Skipped: XCMApp AssetFeePerSecond (max_values: None, max_size: None, mode: Measured)
+	fn set_asset_fee_per_second() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1171`
+		//  Estimated: `4171`
+		// Estimated execution time: 21_000_000 picoseconds.
+		Weight::from_parts(21_000_000, 4171)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: XCMApp TrustedXcmOrigins (r:0 w:1)
+	/// Proof Skipped: XCMApp TrustedXcmOrigins (max_values: None, max_size: None, mode: Measured)
+	fn set_trusted_xcm_origin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Estimated execution time: 18_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: XCMApp PausedDirections (r:1 w:0)
+	/// Proof Skipped: XCMApp PausedDirections (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp PausedAssets (r:1 w:0)
+	/// Proof Skipped: XCMApp PausedAssets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp AssetDust (r:1 w:1)
+	/// Proof Skipped: XCMApp AssetDust (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp Metadata (r:1 w:0)
+	/// Proof Skipped: XCMApp Metadata (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XCMApp Accounting (r:1 w:1)
+	/// Proof Skipped: XCMApp Accounting (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SubstrateBridgeOutboundChannel MessageQueues (r:1 w:1)
+	/// Proof Skipped: SubstrateBridgeOutboundChannel MessageQueues (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SubstrateBridgeOutboundChannel ChannelNonces (r:1 w:0)
+	/// Proof Skipped: SubstrateBridgeOutboundChannel ChannelNonces (max_values: None, max_size: None, mode: Measured)
+	fn claim_asset_dust() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3148`
+		//  Estimated: `6148`
+		// Estimated execution time: 45_000_000 picoseconds.
+		Weight::from_parts(45_000_000, 6148)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: XCMApp TransactAllowedCalls (r:0 w:1)
+	/// Proof Skipped: XCMApp TransactAllowedCalls (max_values: None, max_size: None, mode: Measured)
+	fn set_transact_call_allowed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Estimated execution time: 18_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -592,8 +592,31 @@ impl pallet_collator_selection::Config for Runtime {
     type WeightInfo = ();
 }
 
+orml_traits::parameter_type_with_key! {
+    pub ExistentialDeposits: |_currency_id: parachain_common::primitives::AssetId| -> Balance {
+        0
+    };
+}
+
+impl orml_tokens::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type Amount = i128;
+    type CurrencyId = parachain_common::primitives::AssetId;
+    type WeightInfo = ();
+    type ExistentialDeposits = ExistentialDeposits;
+    type CurrencyHooks = ();
+    type MaxLocks = frame_support::traits::ConstU32<50>;
+    type MaxReserves = frame_support::traits::ConstU32<50>;
+    type ReserveIdentifier = [u8; 8];
+    type DustRemovalWhitelist = frame_support::traits::Nothing;
+}
+
 parameter_types! {
     pub XorAssetId: H256 = H256(hex_literal::hex!("0200000000000000000000000000000000000000000000000000000000000000"));
+    pub const MaxTrappedRetriesPerBlock: u32 = 10;
+    pub const XCMAppDefaultSubNetworkId: SubNetworkId = SubNetworkId::Mainnet;
+    pub const XCMAppSoraDecimals: u8 = 18;
 }
 
 impl xcm_app::Config for Runtime {
@@ -609,8 +632,20 @@ impl xcm_app::Config for Runtime {
     type BalanceConverter = sp_runtime::traits::Identity;
     type XcmSender = XCMSenderWrapper;
     type Currency = Balances;
+    type LocalAssets = Tokens;
     type SelfLocation = xcm_config::SelfLocation;
     type XorAssetId = XorAssetId;
+    type MaxTrappedRetriesPerBlock = MaxTrappedRetriesPerBlock;
+    type ManageOrigin = AtLeastHalfCouncil;
+    type PauseOrigin = EitherOfDiverse<
+        EitherOfDiverse<
+            pallet_collective::EnsureProportionMoreThan<AccountId, TechnicalCollective, 1, 2>,
+            EnsureRoot<AccountId>,
+        >,
+        xcm_config::EnsureRelayChain,
+    >;
+    type DefaultSubNetworkId = XCMAppDefaultSubNetworkId;
+    type SoraDecimals = XCMAppSoraDecimals;
 }
 
 pub struct XCMSenderWrapper;
@@ -1065,6 +1100,7 @@ construct_runtime!(
 
         // ORML
         XTokens: orml_xtokens::{Pallet, Storage, Event<T>} = 41,
+        Tokens: orml_tokens::{Pallet, Storage, Event<T>} = 42,
 
         #[cfg(any(feature = "rococo", feature = "alphanet"))]
         Sudo: pallet_sudo::{Pallet, Call, Storage, Event<T>, Config<T>} = 100,
@@ -1289,6 +1325,36 @@ impl_runtime_apis! {
         }
     }
 
+    impl xcm_app_runtime_api::XcmAppAPI<Block, AccountId> for Runtime {
+        fn trapped_messages(
+            filter: xcm_app::TrappedMessageFilter<AccountId>,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<(u128, xcm_app::TrappedMessage<AccountId>)> {
+            XCMApp::trapped_messages(filter, offset, limit)
+        }
+
+        fn trapped_done_results() -> Vec<H256> {
+            XCMApp::trapped_done_results()
+        }
+
+        fn bridge_asset_trap_nonce() -> u128 {
+            XCMApp::bridge_asset_trap_nonce()
+        }
+
+        fn destination_weight_limit(para_id: u32) -> Option<Weight> {
+            XCMApp::destination_weight_limit(para_id)
+        }
+
+        fn asset_metadata(asset_id: H256) -> Option<xcm_app::AssetMetadata> {
+            XCMApp::asset_metadata(asset_id)
+        }
+
+        fn fee_for_weight(asset_id: H256, weight: Weight) -> Option<u128> {
+            XCMApp::quote_fee(asset_id, weight)
+        }
+    }
+
     impl frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Index> for Runtime {
         fn account_nonce(account: AccountId) -> Index {
             System::account_nonce(account)
@@ -33,11 +33,26 @@ use crate::*;
 #[cfg(feature = "polkadot")]
 use frame_support::{traits::OnRuntimeUpgrade, weights::Weight};
 
+frame_support::parameter_types! {
+    /// Sibling parachains approved by governance to stay trusted XCM origins after the upgrade.
+    /// The relay chain is trusted by the migration itself, other origins are added by governance
+    pub XCMAppTrustedOrigins: sp_std::vec::Vec<xcm::latest::MultiLocation> = sp_std::vec![];
+}
+
 #[cfg(any(feature = "rococo", feature = "alphanet", feature = "kusama"))]
-pub type Migrations = ();
+pub type Migrations = (
+    xcm_app::migrations::v1::InitializeAccounting<crate::Runtime>,
+    xcm_app::migrations::v2::AddNetworkId<crate::Runtime>,
+    xcm_app::migrations::v3::TrustRelayChain<crate::Runtime, XCMAppTrustedOrigins>,
+);
 
 #[cfg(feature = "polkadot")]
-pub type Migrations = (RemoveSudoKey,);
+pub type Migrations = (
+    RemoveSudoKey,
+    xcm_app::migrations::v1::InitializeAccounting<Runtime>,
+    xcm_app::migrations::v2::AddNetworkId<Runtime>,
+    xcm_app::migrations::v3::TrustRelayChain<Runtime, XCMAppTrustedOrigins>,
+);
 
 #[cfg(feature = "polkadot")]
 pub struct RemoveSudoKey;
@@ -28,52 +28,158 @@
 // STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use crate::XCMApp;
+use crate::{AccountId, Balances, PotId, XCMApp, XorAssetId};
+use core::marker::PhantomData;
+use frame_support::traits::{Currency, Imbalance};
+use orml_traits::MultiCurrency;
+use parachain_common::primitives::AssetId;
+use sp_runtime::traits::{AccountIdConversion, Convert};
 use xcm::{latest::Weight as XcmWeight, prelude::*};
+use xcm_builder::TakeRevenue;
 use xcm_executor::{traits::WeightTrader, Assets};
 
-/// Does not take any fees but checks if there are enought assets to pass through the bridge
-pub struct ParachainTrader;
+/// Converts the asset location to the absolute one.
+/// If the location parent is 0, the asset originates from Sora
+fn absolute_location(location: &MultiLocation) -> Option<MultiLocation> {
+    if location.parents == 0 {
+        let mut self_location = crate::xcm_config::SelfLocation::get();
+        self_location.append_with(location.interior).ok()?;
+        Some(self_location)
+    } else {
+        Some(*location)
+    }
+}
+
+/// Charges the fee for the execution weight in the first asset which is acceptable:
+/// it is mapped in XCMApp, its amount is not below the minimum amount to pass through the bridge
+/// and covers the fee. Other assets are left in holding.
+/// Assets without fee per second set in XCMApp can not pay for execution. Collected fee is passed to `R`
+pub struct ParachainTrader<R: TakeRevenue> {
+    /// Weight bought with the fee
+    weight: XcmWeight,
+    /// Location of the asset the fee is paid in and the paid amount
+    paid: Option<(MultiLocation, u128)>,
+    _phantom: PhantomData<R>,
+}
 
-impl WeightTrader for ParachainTrader {
+impl<R: TakeRevenue> ParachainTrader<R> {
+    /// Returns location and amount of the fee for `weight` if the asset can pay for it
+    fn fee_in(
+        &self,
+        asset_id: &xcm::latest::AssetId,
+        amount: u128,
+        weight: XcmWeight,
+    ) -> Result<(MultiLocation, u128), XcmError> {
+        // abstract assets have no fee per second, so they can not pay for execution
+        let Concrete(location) = asset_id else {
+            return Err(XcmError::TooExpensive)
+        };
+        let asset_multilocation = absolute_location(location).ok_or(XcmError::AssetNotFound)?;
+        let minimum_amount =
+            XCMApp::asset_minimum_amount(asset_multilocation).ok_or(XcmError::AssetNotFound)?;
+        let fee =
+            XCMApp::fee_for_weight(&asset_multilocation, weight).ok_or(XcmError::TooExpensive)?;
+        // all the weight should be bought with the same asset to be refunded correctly
+        let other_asset_paid =
+            matches!(self.paid, Some((paid_location, _)) if paid_location != *location);
+        if amount < minimum_amount || fee > amount || other_asset_paid {
+            return Err(XcmError::TooExpensive)
+        }
+        Ok((*location, fee))
+    }
+}
+
+impl<R: TakeRevenue> WeightTrader for ParachainTrader<R> {
     fn new() -> Self {
         log::trace!(target: "xcm::weight", "creating new WeightTrader instance");
-        Self
+        Self { weight: XcmWeight::zero(), paid: None, _phantom: PhantomData }
     }
 
     fn buy_weight(&mut self, weight: XcmWeight, assets: Assets) -> Result<Assets, XcmError> {
         log::trace!(target: "xcm::weight", "buy_weight weight: {:?}, payment: {:?}", weight, assets);
-        if assets.fungible.is_empty() {
-            return Err(XcmError::AssetNotFound)
-        }
-
-        for (asset_id, val) in &assets.fungible {
-            let asset_multilocation = match asset_id {
-                Concrete(m) => {
-                    // check if multilocations parent is 0, it means that an asset originates from Sora
-                    // then convert it to absolute multilocation to check it
-                    if m.parents == 0 {
-                        let mut self_location = crate::xcm_config::SelfLocation::get();
-                        if self_location.append_with(m.interior).is_err() {
-                            return Err(XcmError::AssetNotFound)
-                        }
-                        self_location
-                    } else {
-                        m.clone()
-                    }
+        let mut error = XcmError::AssetNotFound;
+        let fee = assets.fungible.iter().find_map(|(asset_id, amount)| {
+            match self.fee_in(asset_id, *amount, weight) {
+                Ok(fee) => Some(fee),
+                Err(e) => {
+                    log::trace!(target: "xcm::weight", "asset {:?} can not pay: {:?}", asset_id, e);
+                    error = e;
+                    None
                 },
-                _ => return Err(XcmError::AssetNotFound),
-            };
+            }
+        });
+        let Some((location, amount)) = fee else {
+            return Err(error)
+        };
 
-            let Some(minimum_amount) = XCMApp::asset_minimum_amount(asset_multilocation) else {
-                log::trace!(target: "xcm::weight", "asset not found: {:?}", asset_multilocation);
-                return Err(XcmError::AssetNotFound);
-            };
-            if *val < minimum_amount {
-                return Err(XcmError::TooExpensive)
+        let unused = if amount > 0 {
+            assets
+                .checked_sub((location, amount).into())
+                .map_err(|_| XcmError::TooExpensive)?
+        } else {
+            assets
+        };
+        self.weight = self.weight.saturating_add(weight);
+        let (_, paid) = self.paid.get_or_insert((location, 0));
+        *paid = paid.saturating_add(amount);
+        Ok(unused)
+    }
+
+    fn refund_weight(&mut self, weight: XcmWeight) -> Option<MultiAsset> {
+        log::trace!(target: "xcm::weight", "refund_weight weight: {:?}", weight);
+        let (location, paid) = self.paid.as_mut()?;
+        let weight = weight.min(self.weight);
+        let amount = XCMApp::fee_for_weight(&absolute_location(location)?, weight)?.min(*paid);
+        self.weight = self.weight.saturating_sub(weight);
+        *paid = paid.saturating_sub(amount);
+        if amount > 0 {
+            Some((*location, amount).into())
+        } else {
+            None
+        }
+    }
+}
+
+impl<R: TakeRevenue> Drop for ParachainTrader<R> {
+    fn drop(&mut self) {
+        if let Some((location, amount)) = self.paid {
+            if amount > 0 {
+                R::take_revenue((location, amount).into());
             }
         }
+    }
+}
+
+/// Credits collected XCM fees to the collator pot account.
+/// XOR is credited to Balances on the parachain, other assets are sent to the pot account on Sora
+/// through the bridge, so no local supply of them is created
+pub struct ToCollatorPot;
 
-        Ok(assets)
+impl TakeRevenue for ToCollatorPot {
+    fn take_revenue(revenue: MultiAsset) {
+        let MultiAsset { id: Concrete(location), fun: Fungible(amount) } = revenue else {
+            log::error!(target: "xcm::weight", "unsupported revenue: {:?}", revenue);
+            return
+        };
+        let Some(asset_id) = <XCMApp as Convert<MultiLocation, Option<AssetId>>>::convert(location)
+        else {
+            log::error!(target: "xcm::weight", "revenue in unknown asset: {:?}", revenue);
+            return
+        };
+        let pot: AccountId = PotId::get().into_account_truncating();
+        if asset_id == XorAssetId::get() {
+            let credited = Balances::deposit_creating(&pot, amount).peek();
+            if credited != amount {
+                log::error!(
+                    target: "xcm::weight",
+                    "revenue {:?} is credited partially: {}",
+                    revenue,
+                    credited
+                );
+            }
+        } else if let Err(e) = <XCMApp as MultiCurrency<AccountId>>::deposit(asset_id, &pot, amount)
+        {
+            log::error!(target: "xcm::weight", "failed to take revenue {:?}: {:?}", revenue, e);
+        }
     }
 }
@@ -29,12 +29,14 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use super::{
-    AccountId, ParachainInfo, ParachainSystem, PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent,
-    RuntimeOrigin, XcmpQueue,
+    AccountId, OriginCaller, ParachainInfo, ParachainSystem, PolkadotXcm, Runtime, RuntimeCall,
+    RuntimeEvent, RuntimeOrigin, XcmpQueue,
 };
+use codec::Encode;
 use frame_support::{
+    dispatch::{DispatchErrorWithPostInfo, PostDispatchInfo},
     match_types, parameter_types,
-    traits::{Everything, Nothing},
+    traits::{Contains, EnsureOrigin, Everything, Nothing, OriginTrait},
 };
 use orml_traits::{location::AbsoluteReserveProvider, parameter_type_with_key};
 use orml_xcm_support::{IsNativeConcrete, MultiCurrencyAdapter, MultiNativeAsset};
@@ -43,6 +45,7 @@ use parachain_common::primitives::AssetId;
 use polkadot_parachain::primitives::Sibling;
 #[cfg(not(feature = "parachain-gen"))]
 use sp_core::Get;
+use sp_runtime::traits::{AccountIdConversion, Dispatchable};
 use xcm::{latest::Weight as XcmWeight, prelude::*};
 use xcm_builder::{
     AccountId32Aliases, AllowKnownQueryResponses, AllowSubscriptionsFrom,
@@ -50,7 +53,7 @@ use xcm_builder::{
     RelayChainAsNative, SiblingParachainAsNative, SiblingParachainConvertsVia,
     SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit,
 };
-use xcm_executor::XcmExecutor;
+use xcm_executor::{traits::CallDispatcher, XcmExecutor};
 
 #[cfg(feature = "rococo")]
 parameter_types! {
@@ -140,14 +143,24 @@ match_types! {
     };
 }
 
-pub type Barrier = (
-    TakeWeightCredit,
-    AllowTopLevelPaidExecutionFrom<Everything>,
-    // Expected responses are OK.
-    AllowKnownQueryResponses<PolkadotXcm>,
-    // Subscriptions for version tracking allowed only if parent
-    AllowSubscriptionsFrom<OnlyParent>,
-);
+// Messages with paused assets are rejected before the trader takes the fee
+pub type Barrier = xcm_app::DenyPausedAssets<
+    xcm_app::ReportUntrustedOrigin<
+        (
+            TakeWeightCredit,
+            // Paid execution is allowed only for the relay chain and siblings trusted by governance
+            AllowTopLevelPaidExecutionFrom<xcm_app::TrustedXcmOrigin<Runtime>>,
+            // Expected responses are OK.
+            AllowKnownQueryResponses<PolkadotXcm>,
+            // Subscriptions for version tracking allowed only if parent
+            AllowSubscriptionsFrom<OnlyParent>,
+        ),
+        // Rejected paid execution is reported only if the origin is the reason
+        AllowTopLevelPaidExecutionFrom<Everything>,
+        Runtime,
+    >,
+    Runtime,
+>;
 
 pub struct OnlyParent;
 impl frame_support::traits::Contains<MultiLocation> for OnlyParent {
@@ -167,7 +180,7 @@ impl xcm_executor::Config for XcmConfig {
     type IsTeleporter = (); // Teleporting is disabled.
     type Barrier = Barrier;
     type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
-    type Trader = crate::trader::ParachainTrader;
+    type Trader = crate::trader::ParachainTrader<crate::trader::ToCollatorPot>;
     type ResponseHandler = PolkadotXcm;
     type AssetTrap = PolkadotXcm;
     type AssetClaims = PolkadotXcm;
@@ -180,8 +193,92 @@ impl xcm_executor::Config for XcmConfig {
     type FeeManager = ();
     type MessageExporter = ();
     type UniversalAliases = ();
-    type CallDispatcher = RuntimeCall;
-    type SafeCallFilter = ();
+    type CallDispatcher = TransactCallDispatcher;
+    type SafeCallFilter = SafeCallFilter;
+}
+
+/// Calls which can be dispatched with XCM `Transact`.
+/// Calls allowed for each origin are set by governance in XCMApp and checked by `TransactCallDispatcher`
+pub struct SafeCallFilter;
+
+impl Contains<RuntimeCall> for SafeCallFilter {
+    fn contains(call: &RuntimeCall) -> bool {
+        matches!(
+            call,
+            RuntimeCall::XCMApp(
+                xcm_app::Call::set_direction_paused { .. } |
+                    xcm_app::Call::set_asset_paused { .. } |
+                    xcm_app::Call::claim_bridge_asset { .. } |
+                    xcm_app::Call::resend_trapped_done_result { .. }
+            )
+        )
+    }
+}
+
+/// Returns indices of the pallet and the call in the runtime, used to allow calls in XCMApp
+pub fn transact_call_index(call: &RuntimeCall) -> (u8, u8) {
+    call.using_encoded(|encoded| (encoded[0], encoded[1]))
+}
+
+/// Returns location of the relay chain or the sibling parachain which dispatches the call
+/// with XCM `Transact` as its native origin or its sovereign account
+fn transact_origin_location(origin: &RuntimeOrigin) -> Option<MultiLocation> {
+    match origin.caller() {
+        OriginCaller::CumulusXcm(cumulus_pallet_xcm::Origin::Relay) =>
+            Some(MultiLocation::parent()),
+        OriginCaller::CumulusXcm(cumulus_pallet_xcm::Origin::SiblingParachain(para_id)) =>
+            Some(MultiLocation::new(1, X1(Parachain((*para_id).into())))),
+        OriginCaller::system(frame_system::RawOrigin::Signed(account)) =>
+            Sibling::try_from_account(account)
+                .map(|Sibling(para_id)| MultiLocation::new(1, X1(Parachain(para_id.into())))),
+        _ => None,
+    }
+}
+
+/// Dispatches calls of XCM `Transact` only if they are allowed for the origin in XCMApp
+pub struct TransactCallDispatcher;
+
+impl CallDispatcher<RuntimeCall> for TransactCallDispatcher {
+    fn dispatch(
+        call: RuntimeCall,
+        origin: RuntimeOrigin,
+    ) -> Result<PostDispatchInfo, DispatchErrorWithPostInfo<PostDispatchInfo>> {
+        let allowed = transact_origin_location(&origin).map_or(false, |location| {
+            crate::XCMApp::transact_call_allowed(location, transact_call_index(&call)).is_some()
+        });
+        if !allowed {
+            log::trace!(
+                target: "xcm::transact",
+                "call {:?} is not allowed for origin {:?}",
+                call,
+                origin.caller(),
+            );
+            return Err(sp_runtime::DispatchError::from(
+                xcm_app::Error::<Runtime>::TransactCallNotAllowed,
+            )
+            .into())
+        }
+        call.dispatch(origin)
+    }
+}
+
+/// Ensures that the origin is the relay chain, which dispatches calls with XCM `Transact`
+pub struct EnsureRelayChain;
+
+impl EnsureOrigin<RuntimeOrigin> for EnsureRelayChain {
+    type Success = ();
+
+    fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+        match o.clone().into() {
+            Ok(cumulus_pallet_xcm::Origin::Relay) => Ok(()),
+            _ => Err(o),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+        Ok(cumulus_pallet_xcm::Origin::Relay.into())
+    }
 }
 
 /// Converts a local signed origin into an XCM multilocation.
@@ -33,7 +33,7 @@ use bridge_types::{
     substrate::ParachainAppCall, traits::OutboundChannel, GenericTimepoint, SubNetworkId,
 };
 use cumulus_primitives_core::ParaId;
-use frame_support::{assert_ok, traits::Currency};
+use frame_support::{assert_noop, assert_ok, traits::Currency};
 use orml_traits::MultiCurrency;
 use sp_runtime::{traits::AccountIdConversion, AccountId32};
 use xcm_simulator::TestExt;
@@ -105,6 +105,35 @@ fn prepare_sora_parachain() {
             para_x_asset_id(),
             PARA_X_ASSET_MIN_AMOUNT,
         ));
+        // assets without fee per second can not pay for execution,
+        // the minimal rate keeps the fee for test messages negligible
+        for asset_id in [relay_native_asset_id(), para_x_asset_id()] {
+            assert_ok!(crate::XCMApp::set_asset_fee_per_second(
+                crate::RuntimeOrigin::root(),
+                asset_id,
+                Some(1),
+            ));
+            // amounts of assets without metadata can not be converted,
+            // Sora decimals keep the amounts unchanged
+            assert_ok!(crate::XCMApp::set_asset_metadata(
+                crate::RuntimeOrigin::root(),
+                asset_id,
+                Some(xcm_app::AssetMetadata {
+                    name: b"Token".to_vec().try_into().unwrap(),
+                    symbol: b"TKN".to_vec().try_into().unwrap(),
+                    decimals: crate::XCMAppSoraDecimals::get(),
+                    existential_deposit: 0,
+                }),
+            ));
+        }
+
+        for location in [MultiLocation::parent(), MultiLocation::new(1, X1(Parachain(1)))] {
+            assert_ok!(crate::XCMApp::set_trusted_xcm_origin(
+                crate::RuntimeOrigin::root(),
+                location.into(),
+                true,
+            ));
+        }
     });
 }
 
@@ -152,6 +181,60 @@ fn send_relay_chain_asset_to_sora_from_sibling() {
     });
 }
 
+#[test]
+fn send_sibling_asset_to_sora_from_untrusted_sibling_rejected() {
+    TestNet::reset();
+
+    Relay::execute_with(|| {
+        let _ = RelayBalances::deposit_creating(&para_x_account(), 1000000000000000000);
+    });
+
+    prepare_sora_parachain();
+
+    SoraParachain::execute_with(|| {
+        assert_ok!(crate::XCMApp::set_trusted_xcm_origin(
+            crate::RuntimeOrigin::root(),
+            MultiLocation::new(1, X1(Parachain(1))).into(),
+            false,
+        ));
+    });
+
+    ParaX::execute_with(|| {
+        let _ = ParaTokens::set_balance(
+            para_x::RuntimeOrigin::root(),
+            ALICE,
+            CurrencyId::X,
+            999999999999999999999,
+            0,
+        );
+        assert_ok!(ParaXTokens::transfer(
+            Some(ALICE).into(),
+            CurrencyId::X,
+            666555666555666,
+            Box::new(
+                MultiLocation::new(
+                    1,
+                    X2(Parachain(2), Junction::AccountId32 { network: None, id: BOB.into() })
+                )
+                .into()
+            ),
+            WeightLimit::Unlimited
+        ));
+    });
+
+    SoraParachain::execute_with(|| {
+        assert!(frame_system::Pallet::<crate::Runtime>::events().iter().any(|r| r.event ==
+            crate::RuntimeEvent::XCMApp(xcm_app::Event::XcmOriginRejected(
+                MultiLocation::new(1, X1(Parachain(1)))
+            ))));
+
+        assert!(!frame_system::Pallet::<crate::Runtime>::events().iter().any(|r| matches!(
+            r.event,
+            crate::RuntimeEvent::XCMApp(xcm_app::Event::AssetAddedToChannel(_))
+        )));
+    });
+}
+
 #[test]
 fn send_sibling_asset_to_sora_from_sibling() {
     TestNet::reset();
@@ -532,6 +615,64 @@ fn send_relay_chain_asset_to_sora_from_relay_exact_enough_tokens() {
     });
 }
 
+#[test]
+fn send_relay_chain_asset_to_sora_from_relay_pays_fee() {
+    TestNet::reset();
+
+    prepare_sora_parachain();
+
+    SoraParachain::execute_with(|| {
+        assert_ok!(crate::XCMApp::set_asset_fee_per_second(
+            crate::RuntimeOrigin::root(),
+            relay_native_asset_id(),
+            Some(1_000_000_000),
+        ));
+    });
+
+    Relay::execute_with(|| {
+        let _ = RelayBalances::deposit_creating(&ALICE, 1_000_000_000_000_000_000);
+        assert_ok!(relay::XcmPallet::reserve_transfer_assets(
+            Some(ALICE).into(),
+            Box::new(xcm::VersionedMultiLocation::V3(MultiLocation::new(
+                0,
+                X1(Junction::Parachain(2))
+            ))),
+            Box::new(xcm::VersionedMultiLocation::V3(MultiLocation::new(
+                0,
+                X1(Junction::AccountId32 { network: None, id: ALICE.into() })
+            ))),
+            Box::new(xcm::VersionedMultiAssets::V3(
+                vec![xcm::v3::MultiAsset {
+                    id: Concrete(MultiLocation::new(0, Here)),
+                    fun: Fungible(RELAY_ASSET_MIN_AMOUNT * 10),
+                }]
+                .into()
+            )),
+            0,
+        ));
+    });
+
+    SoraParachain::execute_with(|| {
+        let pot: AccountId32 = crate::PotId::get().into_account_truncating();
+        let added_to_channel = |account: &AccountId32| {
+            frame_system::Pallet::<crate::Runtime>::events().into_iter().find_map(|r| {
+                match r.event {
+                    crate::RuntimeEvent::XCMApp(xcm_app::Event::AssetAddedToChannel(
+                        ParachainAppCall::Transfer { recipient, amount, .. },
+                    )) if recipient == *account => Some(amount),
+                    _ => None,
+                }
+            })
+        };
+        let received = added_to_channel(&ALICE).expect("transfer is not sent to the bridge");
+        // the fee is sent to the pot account on Sora
+        let fee = added_to_channel(&pot).expect("fee is not sent to the bridge");
+        assert!(fee > 0);
+        assert_eq!(crate::Tokens::free_balance(relay_native_asset_id(), &pot), 0);
+        assert_eq!(received + fee, RELAY_ASSET_MIN_AMOUNT * 10);
+    });
+}
+
 #[test]
 fn send_sibling_chain_asset_to_sibling_asset_trapped() {
     TestNet::reset();
@@ -543,7 +684,8 @@ fn send_sibling_chain_asset_to_sibling_asset_trapped() {
     prepare_sora_parachain();
 
     SoraParachain::execute_with(|| {
-        let location = xcm::v2::MultiLocation::parent();
+        // relay chain without beneficiary account is not a valid destination
+        let location = MultiLocation::parent();
         let assetid = para_x_asset_id();
 
         // fill queue
@@ -566,7 +708,7 @@ fn send_sibling_chain_asset_to_sibling_asset_trapped() {
             .into(),
             assetid,
             ALICE,
-            xcm::VersionedMultiLocation::V2(location.clone()),
+            xcm::VersionedMultiLocation::V3(location),
             10000000,
         ));
         assert!(!frame_system::Pallet::<crate::Runtime>::events().iter().any(|r| matches!(
@@ -636,7 +778,14 @@ fn claim_refund_bridge_asset_works() {
     SoraParachain::execute_with(|| {
         let assetid = para_x_asset_id();
         let amount = 10000000;
-        crate::XCMApp::trap_asset(Some(message_id()), assetid, ALICE, amount, true);
+        crate::XCMApp::trap_asset(
+            SubNetworkId::Mainnet,
+            Some(message_id()),
+            assetid,
+            ALICE,
+            amount,
+            true,
+        );
         assert_ok!(crate::XCMApp::try_claim_bridge_asset(crate::RuntimeOrigin::root(), 1));
         assert!(crate::XCMApp::bridge_asset_trap(0).is_none());
         assert!(frame_system::Pallet::<crate::Runtime>::events().iter().any(|r| matches!(
@@ -659,7 +808,14 @@ fn claim_bridge_asset_works() {
     SoraParachain::execute_with(|| {
         let assetid = para_x_asset_id();
         let amount = 10000000;
-        crate::XCMApp::trap_asset(Some(message_id()), assetid, ALICE, amount, false);
+        crate::XCMApp::trap_asset(
+            SubNetworkId::Mainnet,
+            Some(message_id()),
+            assetid,
+            ALICE,
+            amount,
+            false,
+        );
         assert_ok!(crate::XCMApp::try_claim_bridge_asset(crate::RuntimeOrigin::root(), 1));
         assert!(crate::XCMApp::bridge_asset_trap(0).is_none());
         assert!(!frame_system::Pallet::<crate::Runtime>::events().iter().any(|r| matches!(
@@ -686,7 +842,14 @@ fn claim_bridge_asset_fails() {
     SoraParachain::execute_with(|| {
         let assetid = para_x_asset_id();
         let amount = 10000000;
-        crate::XCMApp::trap_asset(Some(message_id()), assetid, ALICE, amount, true);
+        crate::XCMApp::trap_asset(
+            SubNetworkId::Mainnet,
+            Some(message_id()),
+            assetid,
+            ALICE,
+            amount,
+            true,
+        );
 
         // fill queue
         for _ in 0..crate::BridgeMaxMessagesPerCommit::get() {
@@ -710,6 +873,65 @@ fn claim_bridge_asset_fails() {
     });
 }
 
+#[test]
+fn claim_bridge_asset_by_signed_account_works() {
+    TestNet::reset();
+
+    Relay::execute_with(|| {
+        let _ = RelayBalances::deposit_creating(&sora_para_account(), 1000000000000000000);
+    });
+
+    prepare_sora_parachain();
+
+    SoraParachain::execute_with(|| {
+        let assetid = para_x_asset_id();
+        let amount = 10000000;
+        crate::XCMApp::trap_asset(
+            SubNetworkId::Mainnet,
+            Some(message_id()),
+            assetid,
+            ALICE,
+            amount,
+            false,
+        );
+        crate::XCMApp::trap_asset(
+            SubNetworkId::Mainnet,
+            Some(message_id()),
+            assetid,
+            ALICE,
+            amount,
+            true,
+        );
+
+        // any account can claim the trapped message, it is still sent to the recipient
+        assert_ok!(crate::XCMApp::claim_bridge_asset(crate::RuntimeOrigin::signed(BOB), 1));
+        assert!(crate::XCMApp::bridge_asset_trap(1).is_none());
+        assert!(frame_system::Pallet::<crate::Runtime>::events().iter().any(|r| r.event ==
+            crate::RuntimeEvent::XCMApp(xcm_app::Event::TrappedMessageSent(
+                ALICE, assetid, amount
+            ))));
+
+        assert_ok!(crate::XCMApp::claim_bridge_asset(crate::RuntimeOrigin::signed(ALICE), 2));
+        assert!(crate::XCMApp::bridge_asset_trap(2).is_none());
+        assert!(frame_system::Pallet::<crate::Runtime>::events().iter().any(|r| r.event ==
+            crate::RuntimeEvent::XCMApp(xcm_app::Event::TrappedMessageRefundSent(
+                message_id(),
+                ALICE,
+                assetid,
+                amount
+            ))));
+
+        assert_noop!(
+            crate::XCMApp::claim_bridge_asset(crate::RuntimeOrigin::signed(ALICE), 2),
+            xcm_app::Error::<crate::Runtime>::TrappedMessageNotFound
+        );
+        assert_noop!(
+            crate::XCMApp::claim_bridge_asset(crate::RuntimeOrigin::root(), 3),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
 #[test]
 fn trap_asset_nonce_works() {
     TestNet::reset();
@@ -720,14 +942,361 @@ fn trap_asset_nonce_works() {
         let assetid = para_x_asset_id();
         let amount = 10000000;
 
-        crate::XCMApp::trap_asset(Some(message_id()), assetid, ALICE, amount, true);
+        crate::XCMApp::trap_asset(
+            SubNetworkId::Mainnet,
+            Some(message_id()),
+            assetid,
+            ALICE,
+            amount,
+            true,
+        );
         assert!(crate::XCMApp::bridge_asset_trap(1).is_some());
 
-        crate::XCMApp::trap_asset(Some(message_id()), assetid, ALICE, amount, true);
+        crate::XCMApp::trap_asset(
+            SubNetworkId::Mainnet,
+            Some(message_id()),
+            assetid,
+            ALICE,
+            amount,
+            true,
+        );
         assert!(crate::XCMApp::bridge_asset_trap(2).is_some());
 
-        crate::XCMApp::trap_asset(Some(message_id()), assetid, ALICE, amount, false);
+        crate::XCMApp::trap_asset(
+            SubNetworkId::Mainnet,
+            Some(message_id()),
+            assetid,
+            ALICE,
+            amount,
+            false,
+        );
         assert!(crate::XCMApp::bridge_asset_trap(3).is_some());
         assert!(crate::XCMApp::bridge_asset_trap(4).is_none());
     });
 }
+
+fn buy_weight(
+    trader: &mut crate::trader::ParachainTrader<crate::trader::ToCollatorPot>,
+    weight: u64,
+    assets: Vec<(MultiLocation, u128)>,
+) -> Result<Assets, XcmError> {
+    let mut payment = Assets::new();
+    for (location, amount) in assets {
+        payment.subsume((location, amount).into());
+    }
+    trader.buy_weight(Weight::from_parts(weight, 0), payment)
+}
+
+fn holding_amount(assets: &Assets, location: MultiLocation) -> Option<u128> {
+    assets.fungible.get(&Concrete(location)).copied()
+}
+
+#[test]
+fn trader_pays_with_registered_asset_and_keeps_unknown_asset() {
+    TestNet::reset();
+
+    prepare_sora_parachain();
+
+    SoraParachain::execute_with(|| {
+        assert_ok!(crate::XCMApp::set_asset_fee_per_second(
+            crate::RuntimeOrigin::root(),
+            relay_native_asset_id(),
+            Some(1_000_000_000_000),
+        ));
+        let relay_location = MultiLocation::new(1, Here);
+        let unknown_location = MultiLocation::new(1, X1(Parachain(3)));
+        let mut trader = crate::trader::ParachainTrader::new();
+
+        let unused = buy_weight(
+            &mut trader,
+            1_000,
+            vec![(relay_location, RELAY_ASSET_MIN_AMOUNT), (unknown_location, 1)],
+        )
+        .expect("trader rejected the message");
+        assert_eq!(holding_amount(&unused, relay_location), Some(RELAY_ASSET_MIN_AMOUNT - 1_000));
+        assert_eq!(holding_amount(&unused, unknown_location), Some(1));
+
+        // unused weight is refunded in the asset the fee has been paid in
+        assert_eq!(
+            trader.refund_weight(Weight::from_parts(400, 0)),
+            Some((relay_location, 400).into())
+        );
+    });
+}
+
+#[test]
+fn trader_skips_asset_below_minimum_amount() {
+    TestNet::reset();
+
+    prepare_sora_parachain();
+
+    SoraParachain::execute_with(|| {
+        let relay_location = MultiLocation::new(1, Here);
+        let para_x_location = MultiLocation::new(
+            1,
+            X2(Parachain(1), GeneralKey { length: 32, data: para_x_general_key() }),
+        );
+        let mut trader = crate::trader::ParachainTrader::new();
+
+        // relay asset comes first in holding, but it is below the minimum amount
+        let unused = buy_weight(
+            &mut trader,
+            1_000,
+            vec![
+                (relay_location, RELAY_ASSET_MIN_AMOUNT - 1),
+                (para_x_location, PARA_X_ASSET_MIN_AMOUNT),
+            ],
+        )
+        .expect("trader rejected the message");
+        assert_eq!(holding_amount(&unused, relay_location), Some(RELAY_ASSET_MIN_AMOUNT - 1));
+        assert_eq!(holding_amount(&unused, para_x_location), Some(PARA_X_ASSET_MIN_AMOUNT));
+    });
+}
+
+#[test]
+fn trader_skips_asset_which_does_not_cover_fee() {
+    TestNet::reset();
+
+    prepare_sora_parachain();
+
+    SoraParachain::execute_with(|| {
+        assert_ok!(crate::XCMApp::set_asset_fee_per_second(
+            crate::RuntimeOrigin::root(),
+            relay_native_asset_id(),
+            Some(1_000_000_000_000_000_000),
+        ));
+        assert_ok!(crate::XCMApp::set_asset_fee_per_second(
+            crate::RuntimeOrigin::root(),
+            para_x_asset_id(),
+            Some(1_000_000_000_000),
+        ));
+        let relay_location = MultiLocation::new(1, Here);
+        let para_x_location = MultiLocation::new(
+            1,
+            X2(Parachain(1), GeneralKey { length: 32, data: para_x_general_key() }),
+        );
+        let mut trader = crate::trader::ParachainTrader::new();
+
+        let unused = buy_weight(
+            &mut trader,
+            1_000,
+            vec![
+                (relay_location, RELAY_ASSET_MIN_AMOUNT),
+                (para_x_location, PARA_X_ASSET_MIN_AMOUNT),
+            ],
+        )
+        .expect("trader rejected the message");
+        assert_eq!(holding_amount(&unused, relay_location), Some(RELAY_ASSET_MIN_AMOUNT));
+        assert_eq!(holding_amount(&unused, para_x_location), Some(PARA_X_ASSET_MIN_AMOUNT - 1_000));
+    });
+}
+
+#[test]
+fn trader_rejects_message_without_acceptable_asset() {
+    TestNet::reset();
+
+    prepare_sora_parachain();
+
+    SoraParachain::execute_with(|| {
+        let relay_location = MultiLocation::new(1, Here);
+        let unknown_location = MultiLocation::new(1, X1(Parachain(3)));
+        let mut trader = crate::trader::ParachainTrader::new();
+
+        assert_eq!(
+            buy_weight(&mut trader, 1_000, vec![(unknown_location, 1_000_000_000)]),
+            Err(XcmError::AssetNotFound)
+        );
+        assert_eq!(
+            buy_weight(
+                &mut trader,
+                1_000,
+                vec![
+                    (relay_location, RELAY_ASSET_MIN_AMOUNT - 1),
+                    (unknown_location, 1_000_000_000)
+                ]
+            ),
+            Err(XcmError::AssetNotFound)
+        );
+        assert_eq!(
+            buy_weight(&mut trader, 1_000, vec![(relay_location, RELAY_ASSET_MIN_AMOUNT - 1)]),
+            Err(XcmError::TooExpensive)
+        );
+    });
+}
+
+#[test]
+fn trader_rejects_unpriced_asset() {
+    TestNet::reset();
+
+    prepare_sora_parachain();
+
+    SoraParachain::execute_with(|| {
+        assert_ok!(crate::XCMApp::set_asset_fee_per_second(
+            crate::RuntimeOrigin::root(),
+            relay_native_asset_id(),
+            None,
+        ));
+        let relay_location = MultiLocation::new(1, Here);
+        let mut trader = crate::trader::ParachainTrader::new();
+
+        assert_eq!(
+            buy_weight(&mut trader, 1_000, vec![(relay_location, RELAY_ASSET_MIN_AMOUNT)]),
+            Err(XcmError::TooExpensive)
+        );
+
+        // abstract assets have no fee per second
+        let mut payment = Assets::new();
+        payment
+            .subsume(MultiAsset { id: Abstract([1; 32]), fun: Fungible(RELAY_ASSET_MIN_AMOUNT) });
+        assert_eq!(
+            trader.buy_weight(Weight::from_parts(1_000, 0), payment),
+            Err(XcmError::TooExpensive)
+        );
+    });
+}
+
+fn transact_message(call: crate::RuntimeCall, origin_kind: OriginKind) -> Xcm<()> {
+    let fees: MultiAsset = (MultiLocation::parent(), RELAY_ASSET_MIN_AMOUNT).into();
+    Xcm(vec![
+        WithdrawAsset(fees.clone().into()),
+        BuyExecution { fees, weight_limit: WeightLimit::Unlimited },
+        Transact {
+            origin_kind,
+            require_weight_at_most: Weight::from_parts(1_000_000_000, 64 * 1024),
+            call: call.encode().into(),
+        },
+    ])
+}
+
+fn send_transact_from_relay(call: crate::RuntimeCall) {
+    Relay::execute_with(|| {
+        assert_ok!(send_xcm::<relay::XcmRouter>(
+            Parachain(2).into(),
+            transact_message(call, OriginKind::Native)
+        ));
+    });
+}
+
+fn send_transact_from_sibling(call: crate::RuntimeCall) {
+    ParaX::execute_with(|| {
+        assert_ok!(send_xcm::<para_x::XcmRouter>(
+            MultiLocation::new(1, X1(Parachain(2))),
+            transact_message(call, OriginKind::SovereignAccount)
+        ));
+    });
+}
+
+fn set_direction_paused_call(direction: xcm_app::TransferDirection) -> crate::RuntimeCall {
+    crate::RuntimeCall::XCMApp(xcm_app::Call::set_direction_paused { direction, paused: true })
+}
+
+fn allow_transact_call(location: MultiLocation, call: &crate::RuntimeCall) {
+    SoraParachain::execute_with(|| {
+        assert_ok!(crate::XCMApp::set_transact_call_allowed(
+            crate::RuntimeOrigin::root(),
+            location.into(),
+            crate::xcm_config::transact_call_index(call),
+            true,
+        ));
+    });
+}
+
+fn para_x_location() -> MultiLocation {
+    MultiLocation::new(1, X1(Parachain(1)))
+}
+
+#[test]
+fn relay_chain_transact_allowed_call_works() {
+    TestNet::reset();
+
+    prepare_sora_parachain();
+
+    let call = set_direction_paused_call(xcm_app::TransferDirection::Outbound);
+    allow_transact_call(MultiLocation::parent(), &call);
+    send_transact_from_relay(call);
+
+    SoraParachain::execute_with(|| {
+        assert_eq!(crate::XCMApp::paused_direction(xcm_app::TransferDirection::Outbound), Some(()));
+    });
+}
+
+#[test]
+fn relay_chain_transact_not_allowed_call_filtered() {
+    TestNet::reset();
+
+    prepare_sora_parachain();
+
+    // the call passes SafeCallFilter, but it is not allowed for the relay chain
+    send_transact_from_relay(set_direction_paused_call(xcm_app::TransferDirection::Outbound));
+
+    SoraParachain::execute_with(|| {
+        assert_eq!(crate::XCMApp::paused_direction(xcm_app::TransferDirection::Outbound), None);
+    });
+}
+
+#[test]
+fn sibling_transact_allowed_call_works() {
+    TestNet::reset();
+
+    prepare_sora_parachain();
+
+    SoraParachain::execute_with(|| {
+        crate::XCMApp::trap_asset(
+            SubNetworkId::Mainnet,
+            Some(message_id()),
+            para_x_asset_id(),
+            ALICE,
+            10000000,
+            false,
+        );
+    });
+
+    let call = crate::RuntimeCall::XCMApp(xcm_app::Call::claim_bridge_asset { nonce: 1 });
+    allow_transact_call(para_x_location(), &call);
+    send_transact_from_sibling(call);
+
+    SoraParachain::execute_with(|| {
+        assert!(crate::XCMApp::bridge_asset_trap(1).is_none());
+    });
+}
+
+#[test]
+fn sibling_transact_relay_chain_call_filtered() {
+    TestNet::reset();
+
+    prepare_sora_parachain();
+
+    let call = set_direction_paused_call(xcm_app::TransferDirection::Inbound);
+    allow_transact_call(MultiLocation::parent(), &call);
+    send_transact_from_sibling(call);
+
+    SoraParachain::execute_with(|| {
+        assert_eq!(crate::XCMApp::paused_direction(xcm_app::TransferDirection::Inbound), None);
+    });
+}
+
+#[test]
+fn transact_call_outside_safe_call_filter_rejected() {
+    TestNet::reset();
+
+    prepare_sora_parachain();
+
+    let call = crate::RuntimeCall::XCMApp(xcm_app::Call::set_destination_fee {
+        para_id: 1,
+        fee: Some(xcm_app::DestinationFee { asset_id: relay_native_asset_id(), amount: 1 }),
+    });
+    // allowing the call for the origin does not bypass SafeCallFilter
+    allow_transact_call(para_x_location(), &call);
+    send_transact_from_sibling(call);
+
+    SoraParachain::execute_with(|| {
+        assert!(frame_system::Pallet::<crate::Runtime>::events().iter().any(|r| matches!(
+            r.event,
+            crate::RuntimeEvent::XcmpQueue(cumulus_pallet_xcmp_queue::Event::Fail {
+                error: XcmError::NoPermission,
+                ..
+            })
+        )));
+        assert_eq!(crate::XCMApp::destination_fee(1), None);
+    });
+}